@@ -1,10 +1,10 @@
 using Avalonia;
 using Avalonia.Controls.ApplicationLifetimes;
 using Avalonia.Markup.Xaml;
-using Avalonia.Styling;
 using Avalonia.Threading;
 using RailReader.Core.Models;
 using RailReader.Core.Services;
+using RailReader2.Services;
 using RailReader2.ViewModels;
 using RailReader2.Views;
 
@@ -57,19 +57,18 @@ public partial class App : Application
                 // Decide BEFORE Load (which may persist a fresh default file), so we only seed
                 // the desktop render-quality default on a true first run (no config yet).
                 bool seedRenderQuality = IsFirstRun();
-                var config = AppConfig.Load();
+                var config = ConfigBackup.LoadWithRecovery();
                 bool configDirty = false;
                 if (seedRenderQuality)
                 {
                     config.RenderQuality = DefaultRenderQuality;
                     configDirty = true;
                 }
-                if (configDirty) config.Save();
-                Application.Current!.RequestedThemeVariant =
-                    config.DarkMode ? ThemeVariant.Dark : ThemeVariant.Light;
+                if (configDirty) config.SaveWithBackup();
                 CleanupService.RunCleanup();
 
                 var vm = new MainWindowViewModel(config);
+                vm.ApplyTheme();
                 var window = new MainWindow { DataContext = vm };
                 vm.SetWindow(window);
 
@@ -77,20 +76,30 @@ public partial class App : Application
                 window.Closing += (_, _) => vm.Dispose();
                 desktop.MainWindow = window;
 
-                // First non-flag argument that names an existing file is the PDF to open.
-                var docPath = args?.FirstOrDefault(a => !a.StartsWith("--") && File.Exists(a));
+                // The first file argument that exists is the PDF to open; the known-state flags
+                // (--page/--zoom/--rail/--fit-width, for agents and scripted launches) apply to it.
+                var options = StartupOptions.Parse(args ?? []);
+                var errors = options.Errors.ToList();
+                var docPath = options.Files.FirstOrDefault(File.Exists);
+                if (docPath is null && options.Files.Count > 0)
+                    errors.Add($"File not found: {options.Files[0]}");
+                else if (docPath is null && options.HasViewRequest)
+                    errors.Add("--page, --zoom, --rail and --fit-width need a PDF to open");
+                foreach (var error in errors)
+                {
+                    RailReaderLogging.Logger.Info($"[Startup] {error}");
+                    vm.ShowError($"Command line: {error}");
+                }
+
                 if (docPath is not null)
                 {
-                    // Optional known-state startup flags (for agents / scripted launches):
-                    //   --page <n> (1-based)   --zoom <percent, e.g. 300>   --rail
-                    var (startPage, startZoom, startRail) = ParseStartupFlags(args!);
                     window.Opened += (_, _) => vm.FireAndForget(OpenStartupDocument(), nameof(vm.OpenDocument));
 
                     async System.Threading.Tasks.Task OpenStartupDocument()
                     {
                         await vm.OpenDocument(docPath);
-                        if (startPage is not null || startZoom is not null || startRail)
-                            vm.ApplyStartupView(startPage, startZoom, startRail);
+                        if (options.HasViewRequest)
+                            vm.ApplyStartupView(options.Page, options.ZoomPercent, options.Rail, options.FitWidth);
                     }
                 }
 
@@ -100,29 +109,4 @@ public partial class App : Application
 
         base.OnFrameworkInitializationCompleted();
     }
-
-    /// <summary>Parse the optional known-state startup flags: <c>--page &lt;n&gt;</c> (1-based),
-    /// <c>--zoom &lt;percent&gt;</c> (e.g. 300 for 300%), and <c>--rail</c>. Unknown/malformed values are
-    /// ignored; the PDF path is a separate positional argument.</summary>
-    private static (int? Page, double? Zoom, bool Rail) ParseStartupFlags(string[] args)
-    {
-        int? page = null;
-        double? zoom = null;
-        bool rail = false;
-        for (int i = 0; i < args.Length; i++)
-        {
-            switch (args[i])
-            {
-                case "--page" when i + 1 < args.Length && int.TryParse(args[i + 1], out var p):
-                    page = p; i++; break;
-                case "--zoom" when i + 1 < args.Length && double.TryParse(
-                        args[i + 1], System.Globalization.NumberStyles.Float,
-                        System.Globalization.CultureInfo.InvariantCulture, out var z):
-                    zoom = z; i++; break;
-                case "--rail":
-                    rail = true; break;
-            }
-        }
-        return (page, zoom, rail);
-    }
 }
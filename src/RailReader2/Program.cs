@@ -1,8 +1,10 @@
+using System.Reflection;
 using System.Runtime.InteropServices;
 using Avalonia;
 using RailReader.Core;
 using RailReader.Core.Services;
 using RailReader.Renderer.Skia;
+using RailReader2.Services;
 
 namespace RailReader2;
 
@@ -18,6 +20,14 @@ internal sealed class Program
     [STAThread]
     public static void Main(string[] args)
     {
+        // --help / --version answer on the console and exit before any window or native setup.
+        var options = StartupOptions.Parse(args);
+        if (options.Help || options.Version)
+        {
+            Console.WriteLine(options.Help ? StartupOptions.HelpText : $"railreader2 {AppVersion}");
+            return;
+        }
+
         if (!OperatingSystem.IsWindows())
             signal(15 /* SIGTERM */, new IntPtr(1) /* SIG_IGN */);
 
@@ -61,6 +71,11 @@ internal sealed class Program
         }
     }
 
+    private static string? AppVersion
+        => typeof(Program).Assembly
+            .GetCustomAttribute<AssemblyInformationalVersionAttribute>()?.InformationalVersion
+            ?? typeof(Program).Assembly.GetName().Version?.ToString();
+
     public static AppBuilder BuildAvaloniaApp()
     {
         var builder = AppBuilder.Configure<App>()
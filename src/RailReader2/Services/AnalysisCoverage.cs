@@ -0,0 +1,87 @@
+using System.Globalization;
+using System.Text;
+using RailReader.Core;
+using RailReader.Core.Models;
+using RailReader.Core.Services;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// How well layout analysis covered a document: per page, the navigable block count, the share of the
+/// page's text that sits inside navigable blocks (what rail mode will actually visit), and how many
+/// blocks the detector was unsure about. Backs Help → Export Analysis Coverage Report, so a reader can
+/// check a critical document before trusting rail mode on it. UI thread only (text extraction goes
+/// through the PDFium-backed per-page cache).
+/// </summary>
+internal static class AnalysisCoverage
+{
+    /// <summary>Blocks detected below this confidence are counted as low-confidence.</summary>
+    internal const float LowConfidenceThreshold = 0.5f;
+
+    /// <summary>Pages whose navigable text share falls below this are flagged for a manual look.</summary>
+    internal const double GapThreshold = 0.9;
+
+    internal readonly record struct PageCoverage(
+        int Page, int NavigableBlocks, int LowConfidenceBlocks, int TextChars, int CoveredChars)
+    {
+        /// <summary>Share of the page's (non-whitespace) text inside navigable blocks; 1 for a page
+        /// with no text, since there is nothing for rail mode to miss.</summary>
+        public double TextCoverage => TextChars == 0 ? 1.0 : Math.Min(1.0, (double)CoveredChars / TextChars);
+
+        /// <summary>Text the rail won't visit: low coverage, or text with no navigable block at all.</summary>
+        public bool HasGap => TextChars > 0 && (NavigableBlocks == 0 || TextCoverage < GapThreshold);
+    }
+
+    /// <summary>Coverage for one analysed page. Characters are counted without whitespace so the
+    /// line breaks and spaces a rect extraction inserts don't skew the ratio; overlapping navigable
+    /// blocks can double-count, which <see cref="PageCoverage.TextCoverage"/> clamps.</summary>
+    internal static PageCoverage ForPage(int page, PageAnalysis analysis, PageText text,
+        IReadOnlySet<BlockRole> navigableRoles)
+    {
+        int navigable = 0, lowConfidence = 0, covered = 0;
+        foreach (var block in analysis.Blocks)
+        {
+            if (block.Confidence < LowConfidenceThreshold) lowConfidence++;
+            if (!navigableRoles.Contains(block.Role)) continue;
+            navigable++;
+            covered += CountNonWhitespace(text.ExtractBlockText(block));
+        }
+
+        int total = CountNonWhitespace(
+            text.ExtractTextInRect(0, 0, (float)analysis.PageWidth, (float)analysis.PageHeight));
+        return new PageCoverage(page, navigable, lowConfidence, total, covered);
+    }
+
+    /// <summary>Coverage for every analysed page of <paramref name="doc"/>, in page order.</summary>
+    internal static List<PageCoverage> Build(DocumentModel doc,
+        IReadOnlyDictionary<int, PageAnalysis> analyses, IReadOnlySet<BlockRole> navigableRoles)
+    {
+        var rows = new List<PageCoverage>(analyses.Count);
+        foreach (var (page, analysis) in analyses.OrderBy(kv => kv.Key))
+            rows.Add(ForPage(page, analysis, doc.GetOrExtractText(page), navigableRoles));
+        return rows;
+    }
+
+    /// <summary>One row per page (1-based page numbers, invariant-culture numbers).</summary>
+    internal static string ToCsv(IEnumerable<PageCoverage> rows)
+    {
+        var sb = new StringBuilder();
+        sb.AppendLine("page,navigable_blocks,text_coverage,low_confidence_blocks,text_chars,flagged");
+        foreach (var r in rows)
+        {
+            sb.Append(CultureInfo.InvariantCulture,
+                $"{r.Page + 1},{r.NavigableBlocks},{r.TextCoverage:0.000},{r.LowConfidenceBlocks},{r.TextChars},");
+            sb.AppendLine(r.HasGap ? "yes" : "no");
+        }
+        return sb.ToString();
+    }
+
+    private static int CountNonWhitespace(string? s)
+    {
+        if (string.IsNullOrEmpty(s)) return 0;
+        int n = 0;
+        foreach (char c in s)
+            if (!char.IsWhiteSpace(c)) n++;
+        return n;
+    }
+}
@@ -0,0 +1,44 @@
+using System.Diagnostics;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Rolling average of how long a view waits for layout analysis: from the moment rail setup goes
+/// pending (page change onto an unanalysed page) until the analysis lands and rail mode is ready.
+/// Core's analysis results carry no timing, so this is measured from the shell side — it includes
+/// queueing behind lookahead as well as inference, which is what the reader actually feels. Shown
+/// in the status bar next to the model indicator. UI thread only.
+/// </summary>
+internal sealed class AnalysisWaitTracker
+{
+    /// <summary>Number of recent waits averaged.</summary>
+    internal const int WindowSize = 10;
+
+    private readonly Queue<double> _samples = new(WindowSize);
+    private double _sum;
+    private long? _pendingSince;
+
+    /// <summary>Average of the last <see cref="WindowSize"/> waits in milliseconds, or null before
+    /// the first one completes.</summary>
+    public double? AverageMs => _samples.Count == 0 ? null : _sum / _samples.Count;
+
+    public void Begin() => Begin(Stopwatch.GetTimestamp());
+
+    public void End() => End(Stopwatch.GetTimestamp());
+
+    /// <summary>Start timing at <paramref name="timestamp"/> (<see cref="Stopwatch"/> ticks). A second
+    /// Begin while already pending keeps the original start: the reader is still waiting.</summary>
+    internal void Begin(long timestamp) => _pendingSince ??= timestamp;
+
+    /// <summary>Record the wait that began at the last <see cref="Begin(long)"/>. No-op when nothing
+    /// is pending (rail setup cleared without a page change, e.g. on tab open).</summary>
+    internal void End(long timestamp)
+    {
+        if (_pendingSince is not { } start) return;
+        _pendingSince = null;
+        double ms = Stopwatch.GetElapsedTime(start, timestamp).TotalMilliseconds;
+        if (_samples.Count == WindowSize) _sum -= _samples.Dequeue();
+        _samples.Enqueue(ms);
+        _sum += ms;
+    }
+}
@@ -0,0 +1,30 @@
+using RailReader.Core.Models;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Text of the hover tooltip shown over a detected block in rail mode: what the layout model called it
+/// and how sure it was, without switching on the full debug overlay.
+/// </summary>
+internal static class BlockHoverInfo
+{
+    /// <summary>Two lines: the model's class name (when the loaded model's class table covers it) with
+    /// the role rail mode maps it to, then confidence, 1-based reading order, and line count.</summary>
+    public static string Describe(LayoutBlock block, IReadOnlyList<string>? classNames)
+    {
+        string role = block.Role.ToString();
+        string name = classNames is not null && block.ClassId >= 0 && block.ClassId < classNames.Count
+            && !string.Equals(classNames[block.ClassId], role, StringComparison.OrdinalIgnoreCase)
+                ? $"{classNames[block.ClassId]} ({role})"
+                : role;
+
+        var details = new List<string>(3)
+        {
+            $"{Math.Round(block.Confidence * 100):F0}% confidence",
+            $"reading order {block.Order + 1}",
+        };
+        if (block.Lines is { Count: > 0 } lines)
+            details.Add(lines.Count == 1 ? "1 line" : $"{lines.Count} lines");
+        return $"{name}\n{string.Join(" · ", details)}";
+    }
+}
@@ -0,0 +1,77 @@
+using System.Text.Json;
+using RailReader.Core;
+using RailReader.Core.Services;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// The same <c>&lt;file&gt;.bak</c> safety net <see cref="JsonSidecar"/> gives the shell's sidecars,
+/// for Core's <c>config.json</c>, whose save and load live in <see cref="AppConfig"/>: the last good
+/// file is copied aside before each save, and at startup a file that no longer parses (a crash
+/// mid-write) is put back from that copy before <see cref="AppConfig.Load"/> would replace it with
+/// defaults.
+/// </summary>
+internal static class ConfigBackup
+{
+    /// <summary><see cref="AppConfig.Save"/>, keeping the file it replaces as the backup.</summary>
+    public static void SaveWithBackup(this AppConfig config)
+    {
+        Backup(AppConfig.ConfigPath);
+        config.Save();
+    }
+
+    /// <summary><see cref="AppConfig.Load"/>, after restoring an unreadable file from its backup.</summary>
+    public static AppConfig LoadWithRecovery()
+    {
+        if (RestoreIfUnreadable(AppConfig.ConfigPath))
+            RailReaderLogging.Logger.Info($"Recovered {Path.GetFileName(AppConfig.ConfigPath)} from its backup");
+        return AppConfig.Load();
+    }
+
+    /// <summary>Copy <paramref name="path"/> to its backup, unless it's missing or already broken (a
+    /// broken file must not overwrite the good copy).</summary>
+    internal static void Backup(string path)
+    {
+        try
+        {
+            if (IsReadable(path))
+                File.Copy(path, JsonSidecar.BackupPath(path), overwrite: true);
+        }
+        catch (Exception ex)
+        {
+            RailReaderLogging.Logger.Error($"Failed to back up {Path.GetFileName(path)}", ex);
+        }
+    }
+
+    /// <summary>Put the backup in place of <paramref name="path"/> when the file exists but doesn't
+    /// parse and the backup does. A missing file is left missing: that's a first run or a reset.</summary>
+    internal static bool RestoreIfUnreadable(string path)
+    {
+        try
+        {
+            var backup = JsonSidecar.BackupPath(path);
+            if (!File.Exists(path) || IsReadable(path) || !IsReadable(backup)) return false;
+            File.Copy(backup, path, overwrite: true);
+            return true;
+        }
+        catch (Exception ex)
+        {
+            RailReaderLogging.Logger.Error($"Failed to restore {Path.GetFileName(path)} from its backup", ex);
+            return false;
+        }
+    }
+
+    private static bool IsReadable(string path)
+    {
+        if (!File.Exists(path)) return false;
+        try
+        {
+            using var doc = JsonDocument.Parse(File.ReadAllText(path));
+            return doc.RootElement.ValueKind == JsonValueKind.Object;
+        }
+        catch (JsonException)
+        {
+            return false;
+        }
+    }
+}
@@ -0,0 +1,87 @@
+using RailReader.Core;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Watches the open PDFs on disk and reports a file once it has stopped changing for
+/// <see cref="Debounce"/> — a LaTeX run rewrites its PDF in several bursts (or writes a temp file and
+/// renames it over), and reloading mid-write would read a truncated file. One
+/// <see cref="FileSystemWatcher"/> per file, on its directory, so rename-over saves are seen too.
+/// The callback runs on a thread-pool thread; the caller marshals to the UI thread.
+/// </summary>
+internal sealed class DocumentFileWatcher : IDisposable
+{
+    internal static readonly TimeSpan Debounce = TimeSpan.FromMilliseconds(750);
+
+    private sealed record Watch(FileSystemWatcher Watcher, Timer Timer);
+
+    private readonly Action<string> _changed;
+    private readonly ILogger _logger;
+    private readonly Dictionary<string, Watch> _watches = new(PathComparer);
+    private readonly object _lock = new();
+
+    private static StringComparer PathComparer =>
+        OperatingSystem.IsWindows() || OperatingSystem.IsMacOS() ? StringComparer.OrdinalIgnoreCase : StringComparer.Ordinal;
+
+    public DocumentFileWatcher(Action<string> changed, ILogger logger)
+    {
+        _changed = changed;
+        _logger = logger;
+    }
+
+    /// <summary>Watch exactly <paramref name="paths"/>: start on new ones, stop on ones no longer open.</summary>
+    public void Sync(IEnumerable<string> paths)
+    {
+        var wanted = new HashSet<string>(paths.Select(Path.GetFullPath), PathComparer);
+        lock (_lock)
+        {
+            foreach (var stale in _watches.Keys.Where(p => !wanted.Contains(p)).ToList())
+                Remove(stale);
+            foreach (var path in wanted)
+                if (!_watches.ContainsKey(path)) Add(path);
+        }
+    }
+
+    public void Dispose()
+    {
+        lock (_lock)
+        {
+            foreach (var path in _watches.Keys.ToList()) Remove(path);
+        }
+    }
+
+    private void Add(string path)
+    {
+        var dir = Path.GetDirectoryName(path);
+        var name = Path.GetFileName(path);
+        if (string.IsNullOrEmpty(dir) || !Directory.Exists(dir)) return;
+        try
+        {
+            var watcher = new FileSystemWatcher(dir, name)
+            {
+                NotifyFilter = NotifyFilters.LastWrite | NotifyFilters.Size | NotifyFilters.FileName,
+            };
+            var timer = new Timer(_ => _changed(path));
+            void Restart() => timer.Change(Debounce, Timeout.InfiniteTimeSpan);
+            watcher.Changed += (_, _) => Restart();
+            watcher.Created += (_, _) => Restart();
+            watcher.Renamed += (_, e) =>
+            {
+                if (PathComparer.Equals(e.FullPath, path)) Restart();
+            };
+            watcher.EnableRaisingEvents = true;
+            _watches[path] = new Watch(watcher, timer);
+        }
+        catch (Exception ex) when (ex is IOException or ArgumentException or UnauthorizedAccessException)
+        {
+            _logger.Debug($"[FileWatch] Can't watch {path}: {ex.Message}");
+        }
+    }
+
+    private void Remove(string path)
+    {
+        if (!_watches.Remove(path, out var watch)) return;
+        watch.Watcher.Dispose();
+        watch.Timer.Dispose();
+    }
+}
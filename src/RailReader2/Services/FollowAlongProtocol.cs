@@ -0,0 +1,118 @@
+using System.Text;
+using System.Text.Json;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Wire format of the follow-along IPC channel: newline-delimited JSON objects, one per line, UTF-8.
+/// All indices are 0-based, matching Core's reading position and the CLI's <c>structure</c> output.
+/// <list type="bullet">
+/// <item>In: <c>{"goto": {"page": 12, "block": 3, "line": 5}}</c> — <c>block</c>/<c>line</c> optional;
+/// <c>line</c> requires <c>block</c>.</item>
+/// <item>Out: <c>{"position": {"page": 12, "block": 3, "line": 5, "text": "…"}}</c> while rail-reading
+/// (block/line/text omitted outside rail mode), or <c>{"error": "…"}</c> for a rejected request.</item>
+/// </list>
+/// Pure string ↔ value conversion, so malformed input is rejected here before anything reaches the UI.
+/// </summary>
+internal static class FollowAlongProtocol
+{
+    /// <summary>Longest accepted request line; anything bigger is not a goto and is rejected unparsed.</summary>
+    internal const int MaxMessageLength = 4096;
+
+    internal readonly record struct GotoRequest(int Page, int? Block, int? Line);
+
+    internal static bool TryParseGoto(string message, out GotoRequest request, out string error)
+    {
+        request = default;
+        if (message.Length > MaxMessageLength)
+        {
+            error = "message too long";
+            return false;
+        }
+
+        try
+        {
+            using var doc = JsonDocument.Parse(message);
+            if (doc.RootElement.ValueKind != JsonValueKind.Object
+                || !doc.RootElement.TryGetProperty("goto", out var target)
+                || target.ValueKind != JsonValueKind.Object)
+            {
+                error = "expected {\"goto\": {\"page\": …}}";
+                return false;
+            }
+
+            if (!TryGetIndex(target, "page", required: true, out var page, out error)
+                || !TryGetIndex(target, "block", required: false, out var block, out error)
+                || !TryGetIndex(target, "line", required: false, out var line, out error))
+                return false;
+            if (line is not null && block is null)
+            {
+                error = "\"line\" requires \"block\"";
+                return false;
+            }
+
+            request = new GotoRequest(page!.Value, block, line);
+            return true;
+        }
+        catch (JsonException)
+        {
+            error = "malformed JSON";
+            return false;
+        }
+    }
+
+    private static bool TryGetIndex(JsonElement obj, string name, bool required, out int? value, out string error)
+    {
+        value = null;
+        error = "";
+        if (!obj.TryGetProperty(name, out var e) || e.ValueKind == JsonValueKind.Null)
+        {
+            if (!required) return true;
+            error = $"\"{name}\" is required";
+            return false;
+        }
+        if (e.ValueKind != JsonValueKind.Number || !e.TryGetInt32(out int v) || v < 0)
+        {
+            error = $"\"{name}\" must be a non-negative integer";
+            return false;
+        }
+        value = v;
+        return true;
+    }
+
+    /// <summary>Rail reading position event. <paramref name="text"/> is omitted when empty.</summary>
+    internal static string FormatPosition(int page, int block, int line, string? text)
+        => Write(w =>
+        {
+            w.WriteStartObject("position");
+            w.WriteNumber("page", page);
+            w.WriteNumber("block", block);
+            w.WriteNumber("line", line);
+            if (!string.IsNullOrEmpty(text)) w.WriteString("text", text);
+            w.WriteEndObject();
+        });
+
+    /// <summary>Page-only position event (not rail-reading).</summary>
+    internal static string FormatPage(int page)
+        => Write(w =>
+        {
+            w.WriteStartObject("position");
+            w.WriteNumber("page", page);
+            w.WriteEndObject();
+        });
+
+    internal static string FormatError(string message)
+        => Write(w => w.WriteString("error", message));
+
+    private static string Write(Action<Utf8JsonWriter> body)
+    {
+        using var stream = new MemoryStream();
+        using (var writer = new Utf8JsonWriter(stream))
+        {
+            writer.WriteStartObject();
+            body(writer);
+            writer.WriteEndObject();
+        }
+        return Encoding.UTF8.GetString(stream.ToArray());
+    }
+}
@@ -0,0 +1,152 @@
+using System.IO.Pipes;
+using System.Text;
+using System.Threading.Channels;
+using RailReader.Core;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Local IPC endpoint for "follow along": an external screen-reader / OCR companion connects, is told
+/// where the rail is on every reading-position change, and may send goto requests to move it (wire
+/// format in <see cref="FollowAlongProtocol"/>). A named pipe restricted to the current user — on
+/// Linux/macOS .NET backs it with a Unix domain socket at <c>$TMPDIR/CoreFxPipe_&lt;name&gt;</c>.
+/// One client at a time; a second waits until the first disconnects.
+///
+/// The accept/read loop runs off the UI thread. Goto requests are handed to the callback on that
+/// thread — the caller must marshal to the UI thread. Outgoing messages go through a bounded queue
+/// so a client that stops reading can never block the UI.
+/// </summary>
+internal sealed class FollowAlongServer : IDisposable
+{
+    /// <summary>Per-user pipe name, so two users on one machine don't collide.</summary>
+    public static string DefaultPipeName => $"railreader2-follow-{Environment.UserName}";
+
+    private static readonly UTF8Encoding s_utf8 = new(encoderShouldEmitUTF8Identifier: false);
+
+    private readonly string _pipeName;
+    private readonly Action<FollowAlongProtocol.GotoRequest> _onGoto;
+    private readonly ILogger _logger;
+    private readonly CancellationTokenSource _cts = new();
+    // Oldest events are dropped once a stalled client lets this fill — only the latest position matters.
+    private readonly Channel<string> _outgoing = Channel.CreateBounded<string>(
+        new BoundedChannelOptions(64) { FullMode = BoundedChannelFullMode.DropOldest, SingleReader = true });
+    private readonly object _stateLock = new();
+    private string? _lastPosition;
+    private bool _connected;
+    private Task? _loop;
+
+    public FollowAlongServer(string pipeName, Action<FollowAlongProtocol.GotoRequest> onGoto, ILogger logger)
+    {
+        _pipeName = pipeName;
+        _onGoto = onGoto;
+        _logger = logger;
+    }
+
+    public void Start()
+    {
+        if (_loop is not null) return;
+        _loop = Task.Run(() => AcceptLoopAsync(_cts.Token));
+        _logger.Debug($"[FollowAlong] Listening on pipe '{_pipeName}'");
+    }
+
+    /// <summary>Publish a position event. Repeats of the last position are skipped; the latest one is
+    /// replayed to each newly connected client so it starts in sync.</summary>
+    public void PublishPosition(string message)
+    {
+        lock (_stateLock)
+        {
+            if (message == _lastPosition) return;
+            _lastPosition = message;
+            if (!_connected) return;
+        }
+        _outgoing.Writer.TryWrite(message);
+    }
+
+    /// <summary>Send a one-off message (e.g. an error reply) to the connected client, if any.</summary>
+    public void Send(string message)
+    {
+        lock (_stateLock)
+            if (!_connected) return;
+        _outgoing.Writer.TryWrite(message);
+    }
+
+    private async Task AcceptLoopAsync(CancellationToken ct)
+    {
+        while (!ct.IsCancellationRequested)
+        {
+            try
+            {
+                await using var pipe = new NamedPipeServerStream(_pipeName, PipeDirection.InOut, 1,
+                    PipeTransmissionMode.Byte, PipeOptions.Asynchronous | PipeOptions.CurrentUserOnly);
+                await pipe.WaitForConnectionAsync(ct);
+                _logger.Debug("[FollowAlong] Client connected");
+                await ServeClientAsync(pipe, ct);
+                _logger.Debug("[FollowAlong] Client disconnected");
+            }
+            catch (OperationCanceledException) when (ct.IsCancellationRequested)
+            {
+                break;
+            }
+            catch (IOException ex)
+            {
+                _logger.Debug($"[FollowAlong] Connection dropped: {ex.Message}");
+            }
+            catch (Exception ex)
+            {
+                _logger.Error("[FollowAlong] Server error", ex);
+                // Back off so a persistent failure (e.g. pipe name in use) doesn't spin.
+                try { await Task.Delay(1000, ct); }
+                catch (OperationCanceledException) { break; }
+            }
+        }
+    }
+
+    private async Task ServeClientAsync(NamedPipeServerStream pipe, CancellationToken ct)
+    {
+        using var clientCts = CancellationTokenSource.CreateLinkedTokenSource(ct);
+        using var reader = new StreamReader(pipe, s_utf8, detectEncodingFromByteOrderMarks: false, leaveOpen: true);
+        await using var writer = new StreamWriter(pipe, s_utf8, leaveOpen: true) { NewLine = "\n", AutoFlush = true };
+
+        // Drop anything queued while no one was listening, then replay the current position.
+        while (_outgoing.Reader.TryRead(out _)) { }
+        lock (_stateLock)
+        {
+            _connected = true;
+            if (_lastPosition is { } last) _outgoing.Writer.TryWrite(last);
+        }
+
+        var pump = PumpOutgoingAsync(writer, clientCts.Token);
+        try
+        {
+            while (await reader.ReadLineAsync(ct) is { } line)
+            {
+                if (string.IsNullOrWhiteSpace(line)) continue;
+                if (FollowAlongProtocol.TryParseGoto(line, out var request, out var error))
+                    _onGoto(request);
+                else
+                    _outgoing.Writer.TryWrite(FollowAlongProtocol.FormatError(error));
+            }
+        }
+        finally
+        {
+            lock (_stateLock) _connected = false;
+            clientCts.Cancel();
+            try { await pump; }
+            catch (OperationCanceledException) { }
+            catch (IOException) { }
+        }
+    }
+
+    private async Task PumpOutgoingAsync(StreamWriter writer, CancellationToken ct)
+    {
+        await foreach (var message in _outgoing.Reader.ReadAllAsync(ct))
+            await writer.WriteLineAsync(message.AsMemory(), ct);
+    }
+
+    public void Dispose()
+    {
+        _cts.Cancel();
+        _outgoing.Writer.TryComplete();
+        _logger.Debug("[FollowAlong] Stopped");
+    }
+}
@@ -8,30 +8,45 @@ namespace RailReader2.Services;
 /// Shared load/save for the shell's small JSON sidecar files (config-dir-resident, source-gen
 /// serialised). Centralises the "read → deserialize-or-default" / "create dir → write" boilerplate +
 /// try/catch logging that <see cref="CustomLayoutModelConfig"/>, <see cref="PortalSet"/>, and
-/// <see cref="PortalWindowSettings"/> would otherwise each copy.
+/// <see cref="PortalWindowSettings"/> would otherwise each copy. Saves are atomic (temp file + rename)
+/// and keep the previous version as <c>&lt;file&gt;.bak</c>, which a load falls back to if the file
+/// itself can't be parsed — so a crash mid-write can't silently reset the settings.
 /// </summary>
 public static class JsonSidecar
 {
     /// <summary>Load <typeparamref name="T"/> from <paramref name="path"/>, returning
-    /// <paramref name="fallback"/>() when the file is absent, empty, or unreadable.</summary>
+    /// <paramref name="fallback"/>() when the file is absent, or when both it and its backup are
+    /// empty or unreadable.</summary>
     public static T Load<T>(string path, JsonTypeInfo<T> typeInfo, Func<T> fallback) where T : class
+    {
+        if (!File.Exists(path)) return fallback();
+        if (TryLoad(path, typeInfo) is { } value) return value;
+        if (TryLoad(BackupPath(path), typeInfo) is { } backup)
+        {
+            RailReaderLogging.Logger.Info($"Recovered {Path.GetFileName(path)} from its backup");
+            return backup;
+        }
+        return fallback();
+    }
+
+    private static T? TryLoad<T>(string path, JsonTypeInfo<T> typeInfo) where T : class
     {
         try
         {
             if (File.Exists(path))
-            {
-                var json = File.ReadAllText(path);
-                return JsonSerializer.Deserialize(json, typeInfo) ?? fallback();
-            }
+                return JsonSerializer.Deserialize(File.ReadAllText(path), typeInfo);
         }
         catch (Exception ex)
         {
             RailReaderLogging.Logger.Error($"Failed to load {Path.GetFileName(path)}", ex);
         }
-        return fallback();
+        return null;
     }
 
-    /// <summary>Serialise <paramref name="value"/> to <paramref name="path"/>, creating its directory.</summary>
+    internal static string BackupPath(string path) => path + ".bak";
+
+    /// <summary>Serialise <paramref name="value"/> to <paramref name="path"/>, creating its directory.
+    /// Written to a temp file beside it first, then swapped in, moving the old file to its backup.</summary>
     public static void Save<T>(string path, T value, JsonTypeInfo<T> typeInfo)
     {
         try
@@ -39,7 +54,12 @@ public static class JsonSidecar
             var dir = Path.GetDirectoryName(path);
             if (!string.IsNullOrEmpty(dir))
                 Directory.CreateDirectory(dir);
-            File.WriteAllText(path, JsonSerializer.Serialize(value, typeInfo));
+            var temp = path + ".tmp";
+            File.WriteAllText(temp, JsonSerializer.Serialize(value, typeInfo));
+            if (File.Exists(path))
+                File.Replace(temp, path, BackupPath(path));
+            else
+                File.Move(temp, path);
         }
         catch (Exception ex)
         {
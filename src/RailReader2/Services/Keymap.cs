@@ -0,0 +1,146 @@
+using Avalonia.Input;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// User key bindings (<c>key_bindings</c> in <c>shell_prefs.json</c>): action name → key chord, e.g.
+/// <c>"next_line": "K"</c> or <c>"go_to_page": "Ctrl+J"</c>. A bound chord is translated to the action's
+/// built-in chord before the window's key handling runs, so it behaves exactly like the default key
+/// (including hold-to-scroll, which sees the translated key-up too). The default keys keep working.
+/// </summary>
+internal sealed class Keymap
+{
+    /// <summary>A rebindable action: its config name, what it does, and its built-in chord.</summary>
+    public sealed record KeyAction(string Name, string Description, KeyGesture Default);
+
+    public static readonly IReadOnlyList<KeyAction> Actions =
+    [
+        new("next_line", "Next line (rail) / pan down", new KeyGesture(Key.Down)),
+        new("previous_line", "Previous line (rail) / pan up", new KeyGesture(Key.Up)),
+        new("scroll_forward", "Scroll along the line, or jump in jump mode (rail) / pan right", new KeyGesture(Key.Right)),
+        new("scroll_backward", "Scroll back along the line, or jump in jump mode (rail) / pan left", new KeyGesture(Key.Left)),
+        new("next_page", "Next page", new KeyGesture(Key.PageDown)),
+        new("previous_page", "Previous page", new KeyGesture(Key.PageUp)),
+        new("go_to_page", "Go to page", new KeyGesture(Key.G, KeyModifiers.Control)),
+        new("navigate_back", "Navigate back", new KeyGesture(Key.OemTilde)),
+        new("zoom_in", "Zoom in", new KeyGesture(Key.OemPlus)),
+        new("zoom_out", "Zoom out", new KeyGesture(Key.OemMinus)),
+        new("fit_page", "Fit page (kept across page changes)", new KeyGesture(Key.D0)),
+        new("fit_width", "Fit width (kept across page changes)", new KeyGesture(Key.D2, KeyModifiers.Control)),
+        new("start_rail_here", "Start rail here at the current zoom (press again or Escape to exit)", new KeyGesture(Key.R)),
+        new("toggle_auto_scroll", "Toggle auto-scroll", new KeyGesture(Key.P)),
+        new("toggle_jump_mode", "Toggle jump mode (saccade-style advance)", new KeyGesture(Key.J)),
+        new("quick_jump", "Quick jump to a block by its label", new KeyGesture(Key.G)),
+        new("line_focus_dim", "Toggle line focus dim", new KeyGesture(Key.F)),
+        new("line_highlight", "Toggle line highlight", new KeyGesture(Key.H)),
+        new("mark_block", "Highlight current block (saved as an annotation)", new KeyGesture(Key.M)),
+        new("read_line_aloud", "Read current line aloud", new KeyGesture(Key.T)),
+        new("add_bookmark", "Add bookmark", new KeyGesture(Key.B)),
+        new("cycle_colour_effect", "Cycle colour effect", new KeyGesture(Key.C)),
+    ];
+
+    private readonly Dictionary<KeyGesture, KeyGesture> _translate;
+
+    /// <summary>The bindings in effect, in <see cref="Actions"/> order, as (action, chord).</summary>
+    public IReadOnlyList<(KeyAction Action, KeyGesture Chord)> Bound { get; }
+
+    /// <summary>Why entries were skipped or what they shadow; empty when the bindings are clean.</summary>
+    public IReadOnlyList<string> Problems { get; }
+
+    private Keymap(Dictionary<KeyGesture, KeyGesture> translate,
+        List<(KeyAction, KeyGesture)> bound, List<string> problems)
+    {
+        _translate = translate;
+        Bound = bound;
+        Problems = problems;
+    }
+
+    public static Keymap Build(IReadOnlyDictionary<string, string>? bindings)
+    {
+        var translate = new Dictionary<KeyGesture, KeyGesture>();
+        var bound = new List<(KeyAction, KeyGesture)>();
+        var problems = new List<string>();
+        if (bindings is null) return new(translate, bound, problems);
+
+        var byName = Actions.ToDictionary(a => a.Name, StringComparer.OrdinalIgnoreCase);
+        var owner = new Dictionary<KeyGesture, KeyAction>();
+        foreach (var (name, chordText) in bindings)
+        {
+            if (!byName.TryGetValue(name, out var action))
+            {
+                problems.Add($"Unknown action \"{name}\"");
+                continue;
+            }
+            if (TryParse(chordText) is not { } chord)
+            {
+                problems.Add($"{action.Name}: can't read key \"{chordText}\"");
+                continue;
+            }
+            if (chord.Equals(action.Default)) continue; // bound to its own default — nothing to do
+            if (owner.TryGetValue(chord, out var other))
+            {
+                problems.Add($"{action.Name}: {chord} is already bound to {other.Name}");
+                continue;
+            }
+            if (Actions.FirstOrDefault(a => a.Default.Equals(chord)) is { } shadowed)
+                problems.Add($"{action.Name}: {chord} replaces its default use ({shadowed.Description})");
+            owner[chord] = action;
+            translate[chord] = action.Default;
+        }
+        foreach (var action in Actions)
+            bound.AddRange(owner.Where(o => o.Value == action).Select(o => (action, o.Key)));
+        return new(translate, bound, problems);
+    }
+
+    /// <summary>Parse a chord like <c>K</c>, <c>Shift+M</c>, or <c>Ctrl+Alt+Left</c>; null when invalid.</summary>
+    public static KeyGesture? TryParse(string? text)
+    {
+        if (string.IsNullOrWhiteSpace(text)) return null;
+        try
+        {
+            var gesture = KeyGesture.Parse(text.Trim());
+            return gesture.Key == Key.None ? null : gesture;
+        }
+        catch (Exception ex) when (ex is ArgumentException or FormatException)
+        {
+            return null;
+        }
+    }
+
+    /// <summary>The user's chords for the action named <paramref name="actionName"/>.</summary>
+    public IEnumerable<KeyGesture> ChordsFor(string actionName)
+        => Bound.Where(b => string.Equals(b.Action.Name, actionName, StringComparison.OrdinalIgnoreCase)).Select(b => b.Chord);
+
+    /// <summary>True when a user binding has taken <paramref name="chord"/>, so it no longer does its
+    /// built-in job.</summary>
+    public bool IsTaken(KeyGesture chord) => _translate.ContainsKey(chord);
+
+    /// <summary>A chord as the shortcuts table writes it: <c>Ctrl+Shift+K</c>, <c>+</c>, <c>`</c>.</summary>
+    public static string Format(KeyGesture chord)
+    {
+        var parts = new List<string>(4);
+        if (chord.KeyModifiers.HasFlag(KeyModifiers.Control)) parts.Add("Ctrl");
+        if (chord.KeyModifiers.HasFlag(KeyModifiers.Alt)) parts.Add("Alt");
+        if (chord.KeyModifiers.HasFlag(KeyModifiers.Shift)) parts.Add("Shift");
+        if (chord.KeyModifiers.HasFlag(KeyModifiers.Meta)) parts.Add("Meta");
+        parts.Add(chord.Key switch
+        {
+            >= Key.D0 and <= Key.D9 => ((char)('0' + (chord.Key - Key.D0))).ToString(),
+            Key.OemPlus => "+",
+            Key.OemMinus => "-",
+            Key.OemTilde => "`",
+            Key.OemComma => ",",
+            Key.OemPeriod => ".",
+            Key.OemOpenBrackets => "[",
+            Key.OemCloseBrackets => "]",
+            Key.PageDown => "PgDn",
+            Key.PageUp => "PgUp",
+            var key => key.ToString(),
+        });
+        return string.Join("+", parts);
+    }
+
+    /// <summary>The built-in chord a pressed chord stands for, or null when it isn't rebound.</summary>
+    public KeyGesture? Translate(Key key, KeyModifiers modifiers)
+        => _translate.Count > 0 && _translate.TryGetValue(new KeyGesture(key, modifiers), out var target) ? target : null;
+}
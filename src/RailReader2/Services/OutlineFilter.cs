@@ -0,0 +1,26 @@
+using RailReader.Core.Models;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Narrows an outline tree to the entries whose title contains a query (case-insensitive), keeping
+/// the ancestors of every match so each stays in context. Returns new entries; the document's
+/// outline isn't touched.
+/// </summary>
+internal static class OutlineFilter
+{
+    public static List<OutlineEntry> Filter(IReadOnlyList<OutlineEntry> entries, string? query)
+    {
+        var q = query?.Trim();
+        if (string.IsNullOrEmpty(q)) return [.. entries];
+
+        var result = new List<OutlineEntry>();
+        foreach (var entry in entries)
+        {
+            var children = Filter(entry.Children, q);
+            if (children.Count > 0 || entry.Title.Contains(q, StringComparison.OrdinalIgnoreCase))
+                result.Add(new OutlineEntry { Title = entry.Title, Page = entry.Page, Children = children });
+        }
+        return result;
+    }
+}
@@ -0,0 +1,103 @@
+using RailReader.Core;
+using RailReader.Core.Models;
+using RailReader.Core.Services;
+using RailReader.Renderer.Skia;
+using SkiaSharp;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Renders pages to PNG files for File → Export Pages as Images — the GUI counterpart of the CLI's
+/// <c>render</c> command, minus annotations. Optionally bakes in a colour effect so the image looks
+/// like the screen. Runs on a background thread, so it needs a PDF handle of its own, not a tab's;
+/// one failed page doesn't stop the rest.
+/// </summary>
+internal static class PageImageExporter
+{
+    internal const int MinDpi = 72, MaxDpi = 1200, DefaultDpi = 300;
+
+    /// <summary>Parse a 1-based page list like <c>1-5,8</c> into sorted, distinct 0-based pages;
+    /// blank means every page. Returns an error message instead for malformed or out-of-range input.</summary>
+    internal static (IReadOnlyList<int>? Pages, string? Error) ParsePages(string? text, int pageCount)
+    {
+        if (string.IsNullOrWhiteSpace(text))
+            return (Enumerable.Range(0, pageCount).ToList(), null);
+
+        var pages = new SortedSet<int>();
+        foreach (var raw in text.Split(',', StringSplitOptions.TrimEntries | StringSplitOptions.RemoveEmptyEntries))
+        {
+            var bounds = raw.Split('-', StringSplitOptions.TrimEntries);
+            if (bounds.Length > 2
+                || !int.TryParse(bounds[0], out int first)
+                || !int.TryParse(bounds[^1], out int last))
+                return (null, $"“{raw}” isn't a page or range");
+            if (first > last) (first, last) = (last, first);
+            if (first < 1 || last > pageCount)
+                return (null, $"“{raw}” is outside pages 1–{pageCount}");
+            for (int p = first; p <= last; p++) pages.Add(p - 1);
+        }
+        return pages.Count == 0 ? (null, "No pages selected") : (pages.ToList(), null);
+    }
+
+    /// <summary>Output file for <paramref name="page"/> (0-based) in a multi-page export:
+    /// <c>&lt;document&gt;_page_007.png</c>, zero-padded to the page count's width (at least 3).</summary>
+    internal static string FileNameFor(string documentPath, int page, int pageCount)
+    {
+        int width = Math.Max(3, pageCount.ToString().Length);
+        return $"{Path.GetFileNameWithoutExtension(documentPath)}_page_{(page + 1).ToString().PadLeft(width, '0')}.png";
+    }
+
+    /// <summary>The files a multi-page export of <paramref name="pages"/> into <paramref name="folder"/>
+    /// would replace, in page order.</summary>
+    internal static IReadOnlyList<string> ExistingOutputs(string folder, string documentPath,
+        IEnumerable<int> pages, int pageCount)
+        => pages.Select(page => Path.Combine(folder, FileNameFor(documentPath, page, pageCount)))
+            .Where(File.Exists)
+            .ToList();
+
+    /// <summary>Render each of <paramref name="pages"/> at <paramref name="dpi"/> to the path
+    /// <paramref name="outputPathFor"/> gives it. <paramref name="progress"/> receives the count done
+    /// so far. Returns how many pages were written and how many failed.</summary>
+    public static (int Written, int Failed) Export(IPdfService pdf, IReadOnlyList<int> pages, int dpi,
+        ColourEffect effect, float intensity, Func<int, string> outputPathFor, IProgress<int>? progress,
+        ILogger logger)
+    {
+        dpi = Math.Clamp(dpi, MinDpi, MaxDpi);
+        // A private shader set: the view's one is only touched on the UI thread.
+        using var shaders = new ColourEffectShaders(logger);
+        var paint = shaders.CreatePaint(effect, intensity);
+
+        int written = 0, failed = 0;
+        foreach (var page in pages)
+        {
+            try
+            {
+                using var rendered = pdf.RenderPage(page, dpi);
+                var bitmap = ((SkiaRenderedPage)rendered).Bitmap;
+                var outputPath = outputPathFor(page);
+                if (paint is null)
+                {
+                    ScreenshotCompositor.SavePng(bitmap, outputPath);
+                }
+                else
+                {
+                    using var surface = SKSurface.Create(new SKImageInfo(bitmap.Width, bitmap.Height));
+                    surface.Canvas.SaveLayer(paint);
+                    surface.Canvas.DrawBitmap(bitmap, 0, 0);
+                    surface.Canvas.Restore();
+                    using var image = surface.Snapshot();
+                    using var filtered = SKBitmap.FromImage(image);
+                    ScreenshotCompositor.SavePng(filtered, outputPath);
+                }
+                written++;
+            }
+            catch (Exception ex)
+            {
+                failed++;
+                logger.Error($"[ExportImages] Page {page + 1} failed", ex);
+            }
+            progress?.Report(written + failed);
+        }
+        return (written, failed);
+    }
+}
@@ -0,0 +1,20 @@
+namespace RailReader2.Services;
+
+/// <summary>
+/// Parses what the user types into the status-bar page field or the Go to Page dialog: an absolute
+/// 1-based page ("247") or a jump relative to the current page ("+10", "-5").
+/// </summary>
+internal static class PageJump
+{
+    /// <summary>The 0-based target page, or null when the input isn't a page number or lands outside
+    /// the document — out-of-range input is rejected rather than clamped so a typo is noticed.</summary>
+    public static int? Resolve(string? text, int currentPage, int pageCount)
+    {
+        var s = text?.Trim();
+        if (string.IsNullOrEmpty(s)) return null;
+        bool relative = s[0] is '+' or '-';
+        if (!int.TryParse(relative ? s[1..].TrimStart() : s, out int n) || n < 0) return null;
+        int target = relative ? currentPage + (s[0] == '+' ? n : -n) : n - 1;
+        return target >= 0 && target < pageCount ? target : null;
+    }
+}
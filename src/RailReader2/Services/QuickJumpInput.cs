@@ -0,0 +1,68 @@
+namespace RailReader2.Services;
+
+/// <summary>One badge of the quick-jump overlay: its label, the page-block index it frames (the value
+/// <c>SmoothlyFrameBlock</c> takes), and the block's top-left corner in page coordinates.</summary>
+internal readonly record struct QuickJumpHint(string Label, int BlockIndex, float X, float Y);
+
+/// <summary>
+/// Label scheme and keystroke matching for quick jump (G): the first nine blocks get "1"–"9", the
+/// rest two-letter labels "aa", "ab", … so a label is never a prefix of another and every jump is at
+/// most two keys. Typing narrows the candidates; an exact label selects, a key no label continues
+/// with is a miss.
+/// </summary>
+internal sealed class QuickJumpInput
+{
+    internal enum Outcome { Pending, Selected, NoMatch }
+
+    private const int DigitLabels = 9;
+    private const int Letters = 26;
+
+    /// <summary>Most labels the scheme can produce; blocks past this get no badge.</summary>
+    internal const int MaxLabels = DigitLabels + Letters * Letters;
+
+    private readonly IReadOnlyList<string> _labels;
+
+    public QuickJumpInput(IReadOnlyList<string> labels) => _labels = labels;
+
+    /// <summary>Keys typed so far toward a two-letter label ("" when none).</summary>
+    public string Typed { get; private set; } = "";
+
+    /// <summary>Labels for <paramref name="count"/> blocks, capped at <see cref="MaxLabels"/>.</summary>
+    public static IReadOnlyList<string> Labels(int count)
+    {
+        count = Math.Clamp(count, 0, MaxLabels);
+        var labels = new string[count];
+        for (int i = 0; i < count; i++)
+        {
+            if (i < DigitLabels)
+            {
+                labels[i] = (i + 1).ToString();
+                continue;
+            }
+            int j = i - DigitLabels;
+            labels[i] = string.Concat((char)('a' + j / Letters), (char)('a' + j % Letters));
+        }
+        return labels;
+    }
+
+    /// <summary>Feed one key. <paramref name="index"/> is the selected label's position on
+    /// <see cref="Outcome.Selected"/>, else -1. A miss clears what was typed.</summary>
+    public Outcome Feed(char key, out int index)
+    {
+        index = -1;
+        var typed = Typed + char.ToLowerInvariant(key);
+        bool prefix = false;
+        for (int i = 0; i < _labels.Count; i++)
+        {
+            if (_labels[i] == typed)
+            {
+                Typed = "";
+                index = i;
+                return Outcome.Selected;
+            }
+            prefix |= _labels[i].StartsWith(typed, StringComparison.Ordinal);
+        }
+        Typed = prefix ? typed : "";
+        return prefix ? Outcome.Pending : Outcome.NoMatch;
+    }
+}
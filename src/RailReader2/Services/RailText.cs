@@ -0,0 +1,58 @@
+using RailReader.Core;
+using RailReader.Core.Models;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Plain text for rail geometry — a detected line or a whole block — read from the page's extracted
+/// text by region. Used by the Ctrl+C / Ctrl+Shift+C copies and by auto-pin's reference parsing.
+/// </summary>
+internal static class RailText
+{
+    /// <summary>Text inside a page-space rectangle, trimmed; null when there is none.</summary>
+    public static string? TextForRegion(PageText text, float left, float top, float right, float bottom)
+        => text.ExtractTextInRect(left, top, right, bottom)?.Trim() is { Length: > 0 } s ? s : null;
+
+    /// <summary>Text of one detected line (<see cref="LineInfo.Y"/> is the line's centre).</summary>
+    public static string? LineText(PageText text, LineInfo line)
+    {
+        float top = line.Y - line.Height / 2f;
+        return TextForRegion(text, line.X, top, line.X + line.Width, top + line.Height);
+    }
+
+    /// <summary>A block's lines as one paragraph. Falls back to the block's box when it has no
+    /// detected lines (e.g. before line detection has run).</summary>
+    public static string? BlockText(PageText text, LayoutBlock block)
+    {
+        if (block.Lines.Count == 0)
+            return TextForRegion(text, block.BBox.X, block.BBox.Y, block.BBox.X + block.BBox.W, block.BBox.Y + block.BBox.H);
+        var joined = JoinLines(block.Lines.Select(l => LineText(text, l)));
+        return joined.Length > 0 ? joined : null;
+    }
+
+    /// <summary>Join lines with single spaces, undoing line-break hyphenation: a line ending in a
+    /// letter + '-' followed by one starting lowercase is glued ("exam-" + "ple" → "example").
+    /// Hyphens before a capital or digit ("Jones-" + "Smith", "pre-" + "2020") are kept and glued
+    /// without a space. Empty lines are skipped.</summary>
+    public static string JoinLines(IEnumerable<string?> lines)
+    {
+        var sb = new System.Text.StringBuilder();
+        foreach (var raw in lines)
+        {
+            var line = raw?.Trim();
+            if (string.IsNullOrEmpty(line)) continue;
+            if (sb.Length == 0)
+            {
+                sb.Append(line);
+                continue;
+            }
+            bool brokenWord = sb.Length >= 2 && sb[^1] == '-' && char.IsLetter(sb[^2]);
+            if (brokenWord && char.IsLower(line[0]))
+                sb.Length--;
+            else if (!brokenWord)
+                sb.Append(' ');
+            sb.Append(line);
+        }
+        return sb.ToString();
+    }
+}
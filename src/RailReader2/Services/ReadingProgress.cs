@@ -0,0 +1,87 @@
+using System.Diagnostics;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// How far through a document the reader is, in rail terms, and a time-remaining estimate from the
+/// recent reading pace. Progress counts whole pages before the current one plus the share of the
+/// current page's navigable lines already passed (block by block). The estimate extrapolates the rate
+/// progress has advanced over the last few minutes of reading; it stays hidden until a minute of data
+/// exists, and jumps (Go to page, outline clicks, going back) or long idle gaps restart the sample so
+/// they don't skew the pace. One per tab; fed from the reading-context callbacks. UI thread only.
+/// </summary>
+internal sealed class ReadingProgress
+{
+    /// <summary>Only the pace over this trailing window counts toward the estimate.</summary>
+    internal static readonly TimeSpan Window = TimeSpan.FromMinutes(5);
+
+    /// <summary>Minimum reading time before an estimate is shown.</summary>
+    internal static readonly TimeSpan MinimumData = TimeSpan.FromMinutes(1);
+
+    /// <summary>A gap between position changes longer than this is a break, not slow reading.</summary>
+    internal static readonly TimeSpan IdleGap = TimeSpan.FromMinutes(2);
+
+    private readonly Queue<(long Timestamp, double Fraction)> _samples = new();
+    private (long Timestamp, double Fraction)? _last;
+
+    /// <summary>Progress through the document, 0–1. <paramref name="block"/>/<paramref name="line"/>
+    /// are the rail's navigable block and line (0-based); pass a zero <paramref name="blockCount"/>
+    /// outside rail mode to count the current page from its top.</summary>
+    public static double Fraction(int page, int pageCount, int block, int blockCount, int line, int lineCount)
+    {
+        if (pageCount <= 0) return 0;
+        double withinPage = 0;
+        if (blockCount > 0)
+        {
+            double withinBlock = lineCount > 0 ? Math.Clamp((double)line / lineCount, 0, 1) : 0;
+            withinPage = Math.Clamp((block + withinBlock) / blockCount, 0, 1);
+        }
+        return Math.Clamp((page + withinPage) / pageCount, 0, 1);
+    }
+
+    public void Record(double fraction, int pageCount) => Record(fraction, pageCount, Stopwatch.GetTimestamp());
+
+    public TimeSpan? EstimateRemaining(double fraction) => EstimateRemaining(fraction, Stopwatch.GetTimestamp());
+
+    /// <summary>Add a position sample at <paramref name="timestamp"/> (<see cref="Stopwatch"/> ticks).
+    /// Moving backwards, advancing more than a page in one step, or resuming after an idle gap starts
+    /// a fresh pace sample.</summary>
+    internal void Record(double fraction, int pageCount, long timestamp)
+    {
+        if (_last is { } last)
+        {
+            double step = fraction - last.Fraction;
+            bool jumped = step < 0 || (pageCount > 0 && step > 1.0 / pageCount);
+            if (jumped || Stopwatch.GetElapsedTime(last.Timestamp, timestamp) > IdleGap)
+                _samples.Clear();
+        }
+        _last = (timestamp, fraction);
+        _samples.Enqueue((timestamp, fraction));
+        while (_samples.Count > 1 && Stopwatch.GetElapsedTime(_samples.Peek().Timestamp, timestamp) > Window)
+            _samples.Dequeue();
+    }
+
+    /// <summary>Time left at the recent pace, or null while there's under <see cref="MinimumData"/>
+    /// of reading in the window, or no forward progress in it.</summary>
+    internal TimeSpan? EstimateRemaining(double fraction, long now)
+    {
+        if (_samples.Count < 2 || _last is not { } last) return null;
+        // The reader has stepped away — a stale pace would only mislead.
+        if (Stopwatch.GetElapsedTime(last.Timestamp, now) > IdleGap) return null;
+        var first = _samples.Peek();
+        var span = Stopwatch.GetElapsedTime(first.Timestamp, last.Timestamp);
+        double advanced = last.Fraction - first.Fraction;
+        if (span < MinimumData || advanced <= 0) return null;
+        double perSecond = advanced / span.TotalSeconds;
+        return TimeSpan.FromSeconds(Math.Max(0, 1 - fraction) / perSecond);
+    }
+
+    /// <summary>Compact status-bar form: "~1 h 20 min left", "~25 min left", "&lt;1 min left".</summary>
+    public static string FormatRemaining(TimeSpan remaining)
+    {
+        int minutes = (int)Math.Round(remaining.TotalMinutes);
+        if (minutes < 1) return "<1 min left";
+        if (minutes < 60) return $"~{minutes} min left";
+        return minutes % 60 == 0 ? $"~{minutes / 60} h left" : $"~{minutes / 60} h {minutes % 60} min left";
+    }
+}
@@ -0,0 +1,116 @@
+using System.Diagnostics;
+using System.Globalization;
+using System.Text;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Where reading time goes in one tab: seconds spent with each (page, navigable block) seated while
+/// rail-reading, plus how many lines were stepped through. Time is credited from one reading-context
+/// change to the next, so nothing ticks per frame; a gap longer than <see cref="IdleTimeout"/> only
+/// counts up to the timeout (the reader has walked away), and <see cref="Pause"/> stops the clock
+/// outright (rail off, window in the background). In-memory for the session. UI thread only.
+/// </summary>
+internal sealed class ReadingStats
+{
+    internal const int DefaultIdleMinutes = 2;
+
+    internal readonly record struct Entry(int Page, int Block, double Seconds);
+
+    private readonly Dictionary<(int Page, int Block), double> _seconds = new();
+    private (int Page, int Block, int Line)? _current;
+    private long _since;
+
+    /// <summary>Longest stretch without navigation that still counts as reading.</summary>
+    public TimeSpan IdleTimeout { get; set; } = TimeSpan.FromMinutes(DefaultIdleMinutes);
+
+    public int LinesRead { get; private set; }
+
+    public double TotalSeconds => _seconds.Values.Sum();
+
+    public int PagesVisited => _seconds.Keys.Select(k => k.Page).Distinct().Count();
+
+    /// <summary>Average time per line stepped through, or null before the first step.</summary>
+    public double? SecondsPerLine => LinesRead > 0 ? TotalSeconds / LinesRead : null;
+
+    public void Mark(int page, int block, int line) => Mark(page, block, line, Stopwatch.GetTimestamp());
+
+    public void Pause() => Pause(Stopwatch.GetTimestamp());
+
+    /// <summary>Credit the time since the last mark to whatever was seated then, and bring the
+    /// running interval up to now without counting a line (before reading the totals).</summary>
+    public void Flush() => Flush(Stopwatch.GetTimestamp());
+
+    /// <summary>The rail now sits on (<paramref name="page"/>, <paramref name="block"/>,
+    /// <paramref name="line"/>) at <paramref name="timestamp"/> (<see cref="Stopwatch"/> ticks). Only
+    /// a forward step counts as a line read: a later line in the same block, or the first line of the
+    /// next block (the first block of the next page included). Going back, re-seating, and jumps
+    /// don't.</summary>
+    internal void Mark(int page, int block, int line, long timestamp)
+    {
+        Credit(timestamp);
+        if (_current is { } previous && IsForwardStep(previous, page, block, line)) LinesRead++;
+        _current = (page, block, line);
+        _since = timestamp;
+    }
+
+    private static bool IsForwardStep((int Page, int Block, int Line) previous, int page, int block, int line)
+    {
+        if (page == previous.Page && block == previous.Block) return line > previous.Line;
+        if (line != 0) return false;
+        return (page == previous.Page && block == previous.Block + 1)
+            || (page == previous.Page + 1 && block == 0);
+    }
+
+    internal void Pause(long timestamp)
+    {
+        Credit(timestamp);
+        _current = null;
+    }
+
+    internal void Flush(long timestamp)
+    {
+        Credit(timestamp);
+        _since = timestamp;
+    }
+
+    public void Reset()
+    {
+        _seconds.Clear();
+        LinesRead = 0;
+        _current = null;
+    }
+
+    /// <summary>Seconds per page, in page order (0-based pages).</summary>
+    public IReadOnlyList<(int Page, double Seconds)> PerPage()
+        => _seconds.GroupBy(kv => kv.Key.Page)
+            .Select(g => (g.Key, g.Sum(kv => kv.Value)))
+            .OrderBy(p => p.Key)
+            .ToList();
+
+    public IReadOnlyList<Entry> Entries()
+        => _seconds.OrderBy(kv => kv.Key.Page).ThenBy(kv => kv.Key.Block)
+            .Select(kv => new Entry(kv.Key.Page, kv.Key.Block, kv.Value))
+            .ToList();
+
+    /// <summary>One <c>page,block,seconds</c> row per block read; page and block are 1-based, the
+    /// block counted among the page's navigable blocks.</summary>
+    internal string ToCsv()
+    {
+        var sb = new StringBuilder();
+        sb.AppendLine("page,block,seconds");
+        foreach (var e in Entries())
+            sb.AppendLine(CultureInfo.InvariantCulture, $"{e.Page + 1},{e.Block + 1},{e.Seconds:0.0}");
+        return sb.ToString();
+    }
+
+    private void Credit(long timestamp)
+    {
+        if (_current is not { } current) return;
+        var elapsed = Stopwatch.GetElapsedTime(_since, timestamp);
+        if (elapsed <= TimeSpan.Zero) return;
+        if (elapsed > IdleTimeout) elapsed = IdleTimeout;
+        var key = (current.Page, current.Block);
+        _seconds[key] = _seconds.GetValueOrDefault(key) + elapsed.TotalSeconds;
+    }
+}
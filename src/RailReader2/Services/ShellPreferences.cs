@@ -0,0 +1,64 @@
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using RailReader.Core;
+using RailReader.Core.Services;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// App-level shell behaviour toggles that have no home in Core's <see cref="AppConfig"/> (a NuGet type
+/// we don't extend). Shell-managed sidecar (<c>ConfigDir/shell_prefs.json</c>) like
+/// <see cref="PortalPreferences"/>; feature-specific settings with their own lifecycle (portal window
+/// bounds, custom model) keep their own files.
+/// </summary>
+public sealed class ShellPreferences
+{
+    /// <summary>Run the local follow-along IPC server (<see cref="FollowAlongServer"/>) so an external
+    /// screen-reader/OCR companion can track and drive the rail. Off by default.</summary>
+    public bool FollowAlongEnabled { get; set; }
+
+    /// <summary>Allow reading the current rail line aloud (<c>T</c>) via <see cref="SpeechService"/>.</summary>
+    public bool SpeechEnabled { get; set; } = true;
+
+    /// <summary>Read-aloud speaking rate in words per minute.</summary>
+    public int SpeechWordsPerMinute { get; set; } = SpeechService.DefaultWordsPerMinute;
+
+    /// <summary>Reading stats stop counting after this many minutes without navigation.</summary>
+    public int ReadingStatsIdleMinutes { get; set; } = ReadingStats.DefaultIdleMinutes;
+
+    /// <summary>Follow the operating system's light/dark setting instead of Core's <c>dark_mode</c>.</summary>
+    public bool FollowSystemTheme { get; set; }
+
+    /// <summary>Reload open documents when their file changes on disk (<see cref="DocumentFileWatcher"/>).</summary>
+    public bool AutoReload { get; set; } = true;
+
+    /// <summary>Show a detected block's class and confidence in a tooltip when hovered in rail mode.</summary>
+    public bool BlockHoverInfo { get; set; } = true;
+
+    /// <summary>Plain wheel scrolls (and Ctrl+wheel zooms) instead of the default wheel-zooms.</summary>
+    public bool WheelScrolls { get; set; }
+
+    /// <summary>Reverse the direction of wheel scrolling (not zooming).</summary>
+    public bool InvertScroll { get; set; }
+
+    /// <summary>Multiplier on how far one wheel notch zooms or scrolls.</summary>
+    public double WheelSensitivity { get; set; } = 1.0;
+
+    /// <summary>Extra key chords for built-in actions, by action name (see <see cref="Keymap"/>).</summary>
+    public Dictionary<string, string> KeyBindings { get; set; } = [];
+
+    public static string Path => System.IO.Path.Combine(AppConfig.ConfigDir, "shell_prefs.json");
+
+    public static ShellPreferences Load()
+        => JsonSidecar.Load(Path, ShellPreferencesJsonContext.Default.ShellPreferences,
+            static () => new ShellPreferences());
+
+    public void Save()
+        => JsonSidecar.Save(Path, this, ShellPreferencesJsonContext.Default.ShellPreferences);
+}
+
+[JsonSourceGenerationOptions(
+    PropertyNamingPolicy = JsonKnownNamingPolicy.SnakeCaseLower,
+    WriteIndented = true)]
+[JsonSerializable(typeof(ShellPreferences))]
+internal partial class ShellPreferencesJsonContext : JsonSerializerContext;
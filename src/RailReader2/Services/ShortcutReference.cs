@@ -0,0 +1,140 @@
+namespace RailReader2.Services;
+
+/// <summary>
+/// The F1 shortcuts table. Rows for rebindable actions name their <see cref="Keymap"/> action and take
+/// their description from <see cref="Keymap.Actions"/>, so the table shows the keys actually in effect:
+/// the user's chords, then the built-in chord unless a binding has taken it, then any fixed aliases
+/// (Space, WASD) that aren't part of the keymap.
+/// </summary>
+internal static class ShortcutReference
+{
+    /// <summary>One line of the table. <see cref="Action"/> is null for keys that can't be rebound;
+    /// for action rows <see cref="Keys"/> holds only the extra aliases.</summary>
+    internal sealed record Row(string Keys, string Description, string? Action = null);
+
+    internal sealed record Section(string Title, IReadOnlyList<Row> Rows);
+
+    private static Row Bound(string action, string alsoKeys = "") => new(alsoKeys, "", action);
+
+    public static readonly IReadOnlyList<Section> Sections =
+    [
+        new("General",
+        [
+            new("Ctrl+O", "Open file"),
+            new("Ctrl+W", "Close tab"),
+            new("Ctrl+Q", "Quit"),
+            new("Ctrl+Tab", "Next tab"),
+            new("F1", "Toggle this dialog"),
+            new("F5", "Reload document from disk"),
+            Bound("add_bookmark"),
+        ]),
+        new("Navigation",
+        [
+            Bound("next_page", "Space"),
+            Bound("previous_page"),
+            Bound("go_to_page"),
+            Bound("navigate_back"),
+            new("Home", "First page (line start in rail)"),
+            new("End", "Last page (line end in rail)"),
+            new("Ctrl+Home", "First page"),
+            new("Ctrl+End", "Last page"),
+            new("Ctrl+Shift+H", "Jump to next heading (rail mode; menu for previous)"),
+            new("Ctrl+Shift+G", "Jump to next figure"),
+            new("Ctrl+Shift+T", "Jump to next table"),
+            new("Ctrl+Shift+E", "Jump to next equation"),
+        ]),
+        new("View",
+        [
+            Bound("zoom_in", "="),
+            Bound("zoom_out"),
+            Bound("fit_page"),
+            Bound("fit_width"),
+            Bound("cycle_colour_effect"),
+            new("Ctrl+Shift+O", "Toggle outline"),
+            new("Ctrl+Shift+I", "Toggle figures panel"),
+            new("Ctrl+M", "Toggle minimap (drag top to move, corner to resize)"),
+            new("Ctrl+Shift+M", "Toggle margin cropping"),
+            new("F11", "Toggle fullscreen"),
+            new("Ctrl+,", "Settings"),
+            new("Shift+D", "Toggle debug overlay"),
+        ]),
+        new("Search",
+        [
+            new("Ctrl+F", "Open find bar"),
+            new("Enter", "Next match"),
+            new("Shift+Enter / Shift+F3", "Previous match"),
+            new("Escape", "Close find bar"),
+        ]),
+        new("Rail Mode (active above zoom threshold)",
+        [
+            Bound("next_line", "S"),
+            Bound("previous_line", "W"),
+            Bound("scroll_forward", "D"),
+            Bound("scroll_backward", "A"),
+            new("Shift+Right / Shift+Left", "Short jump (half distance, jump mode)"),
+            new("Home", "Snap to line start"),
+            new("End", "Snap to line end"),
+            Bound("toggle_auto_scroll"),
+            new("Escape", "Stop auto-scroll"),
+            Bound("toggle_jump_mode"),
+            Bound("line_focus_dim"),
+            new("Shift+F", "Fit current block to view width"),
+            Bound("line_highlight"),
+            Bound("mark_block"),
+            new("Shift+M", "Highlight current line"),
+            Bound("read_line_aloud"),
+            Bound("quick_jump"),
+            new("[ / ]", "Adjust scroll speed (±5)"),
+            new("Ctrl+[ / Ctrl+]", "Fine adjust scroll speed (±1)"),
+            new("Shift+[ / Shift+]", "Adjust blur intensity (±0.05)"),
+            new("Ctrl+Shift+[ / ]", "Fine adjust blur intensity (±0.01)"),
+            new("Click", "Jump to block"),
+            new("Ctrl+Drag", "Free pan (release Ctrl to snap back)"),
+        ]),
+        new("Pan (below zoom threshold)",
+        [
+            new("WASD", "Pan"),
+            new("Click+drag", "Pan"),
+            Bound("start_rail_here"),
+        ]),
+        new("Annotations",
+        [
+            new("Ctrl+E", "Toggle annotation mode (shows the toolbar)"),
+            new("1", "Highlight tool (enters annotation mode)"),
+            new("2", "Pen tool"),
+            new("3", "Rectangle tool"),
+            new("4", "Text note tool"),
+            new("5", "Eraser tool"),
+            new("Right-click", "Context menu (block actions, annotation mode)"),
+            new("Escape", "Cancel tool / exit annotation mode"),
+            new("Ctrl+Z", "Undo annotation"),
+            new("Ctrl+Shift+Z", "Redo annotation"),
+            new("Ctrl+C", "Copy selected text (no selection: current rail line)"),
+            new("Ctrl+Shift+C", "Copy current rail block as plain text"),
+        ]),
+        new("VLM (requires configured endpoint)",
+        [
+            new("Ctrl+L", "Copy current block as LaTeX / Markdown / description"),
+            new("Right-click", "Copy clicked block as LaTeX / Markdown / description"),
+        ]),
+    ];
+
+    /// <summary>The key column for <paramref name="row"/> under <paramref name="keymap"/>.</summary>
+    public static string KeysFor(Row row, Keymap keymap)
+    {
+        if (row.Action is null) return row.Keys;
+        var action = ActionFor(row);
+        var keys = keymap.ChordsFor(action.Name).Select(Keymap.Format).ToList();
+        if (!keymap.IsTaken(action.Default)) keys.Add(Keymap.Format(action.Default));
+        // A fixed alias stops working once a binding takes it, same as the default.
+        keys.AddRange(row.Keys.Split(" / ", StringSplitOptions.RemoveEmptyEntries)
+            .Where(alias => Keymap.TryParse(alias) is not { } chord || !keymap.IsTaken(chord)));
+        return string.Join(" / ", keys);
+    }
+
+    /// <summary>The description column for <paramref name="row"/>.</summary>
+    public static string DescriptionFor(Row row) => row.Action is null ? row.Description : ActionFor(row).Description;
+
+    private static Keymap.KeyAction ActionFor(Row row)
+        => Keymap.Actions.First(a => a.Name == row.Action);
+}
@@ -0,0 +1,213 @@
+using System.Diagnostics;
+using System.Globalization;
+using System.Runtime.InteropServices;
+using RailReader.Core;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// Speaks a line of text through the platform's own speech tool, so there's no audio dependency to
+/// ship: espeak-ng / espeak / speech-dispatcher (<c>spd-say</c>) on Linux, <c>say</c> on macOS, and
+/// System.Speech via PowerShell on Windows. Text always goes over stdin or after an end-of-options
+/// marker, never through a shell. One utterance at a time — a new one (or <see cref="Stop"/>) cuts
+/// off the previous. The processes are started and stopped on a background queue, so the UI never waits
+/// on them. When no backend is found, <see cref="SpeakAsync"/> completes with false and the caller
+/// says so; nothing throws.
+/// </summary>
+internal sealed class SpeechService : IDisposable
+{
+    internal enum Backend { None, EspeakNg, Espeak, SpdSay, Say, WindowsSapi }
+
+    /// <summary>A ready-to-launch process: executable, argument list, and text for stdin (null when
+    /// the text is passed as an argument).</summary>
+    internal readonly record struct SpeechCommand(string FileName, IReadOnlyList<string> Arguments, string? StandardInput);
+
+    /// <summary>Speaking rate range offered in Settings, in words per minute.</summary>
+    internal const int MinWordsPerMinute = 80, MaxWordsPerMinute = 400, DefaultWordsPerMinute = 180;
+
+    // spd-say --cancel returns as soon as the daemon has the request — normally a few milliseconds.
+    // Bounded so a wedged speech-dispatcher can't hold up the speech queue, or quitting, for long.
+    private static readonly TimeSpan SpdCancelTimeout = TimeSpan.FromMilliseconds(500);
+
+    private readonly ILogger _logger;
+    private readonly Lazy<Backend> _backend;
+    private readonly object _queueLock = new();
+    private Task _queue = Task.CompletedTask;
+    private Process? _current;
+    private bool _daemonSpeaking;
+
+    public SpeechService(ILogger logger)
+    {
+        _logger = logger;
+        _backend = new Lazy<Backend>(Detect);
+    }
+
+    /// <summary>False when no speech tool was found on this machine.</summary>
+    public bool IsAvailable => _backend.Value != Backend.None;
+
+    /// <summary>Speak <paramref name="text"/>, interrupting anything still being spoken. Completes
+    /// with false when there's no backend or it failed to start.</summary>
+    public Task<bool> SpeakAsync(string text, int wordsPerMinute)
+    {
+        if (BuildCommand(_backend.Value, text, wordsPerMinute) is not { } cmd)
+        {
+            Stop();
+            return Task.FromResult(false);
+        }
+        return Enqueue(() =>
+        {
+            StopCurrent();
+            return Start(cmd);
+        });
+    }
+
+    /// <summary>Cut off the current utterance, if any.</summary>
+    public void Stop() => _ = Enqueue(() =>
+    {
+        StopCurrent();
+        return true;
+    });
+
+    public void Dispose()
+    {
+        Stop();
+        // Give a pending spd-say cancel the chance to reach the daemon before the app goes.
+        _queue.Wait(SpdCancelTimeout);
+    }
+
+    /// <summary>Run <paramref name="work"/> on the thread pool after everything queued before it, so
+    /// killing, cancelling and launching never block the UI and always happen in the order asked.</summary>
+    private Task<bool> Enqueue(Func<bool> work)
+    {
+        lock (_queueLock)
+        {
+            var next = _queue.ContinueWith(_ => work(), CancellationToken.None,
+                TaskContinuationOptions.None, TaskScheduler.Default);
+            _queue = next;
+            return next;
+        }
+    }
+
+    /// <summary>Launch <paramref name="cmd"/>. Runs on the queue, as does <see cref="StopCurrent"/>.</summary>
+    private bool Start(SpeechCommand cmd)
+    {
+        try
+        {
+            var psi = new ProcessStartInfo(cmd.FileName)
+            {
+                UseShellExecute = false,
+                CreateNoWindow = true,
+                RedirectStandardInput = cmd.StandardInput is not null,
+            };
+            foreach (var arg in cmd.Arguments) psi.ArgumentList.Add(arg);
+            var process = Process.Start(psi);
+            if (process is null) return false;
+            if (cmd.StandardInput is { } input)
+            {
+                process.StandardInput.Write(input);
+                process.StandardInput.Close();
+            }
+            _current = process;
+            _daemonSpeaking = _backend.Value == Backend.SpdSay;
+            return true;
+        }
+        catch (Exception ex)
+        {
+            _logger.Error($"[Speech] Failed to start {cmd.FileName}", ex);
+            return false;
+        }
+    }
+
+    private void StopCurrent()
+    {
+        if (_current is { } process)
+        {
+            _current = null;
+            try
+            {
+                if (!process.HasExited) process.Kill(entireProcessTree: true);
+            }
+            catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception)
+            {
+                // Exited between the check and the kill.
+            }
+            process.Dispose();
+        }
+        // spd-say only hands the text to the speech-dispatcher daemon; killing the client doesn't
+        // silence it, so cancel on the daemon side too — only when something was handed over since
+        // the last cancel. Wait for the cancel to be delivered, or it could reach the daemon after the
+        // text of the launch queued behind it and silence that.
+        if (_daemonSpeaking)
+        {
+            _daemonSpeaking = false;
+            TryRun("spd-say", SpdCancelTimeout, "--cancel");
+        }
+    }
+
+    /// <summary>The process to launch for <paramref name="backend"/>, or null for
+    /// <see cref="Backend.None"/> / blank text. The rate is clamped to the Settings range and mapped
+    /// onto each tool's own scale.</summary>
+    internal static SpeechCommand? BuildCommand(Backend backend, string text, int wordsPerMinute)
+    {
+        if (string.IsNullOrWhiteSpace(text)) return null;
+        int wpm = Math.Clamp(wordsPerMinute, MinWordsPerMinute, MaxWordsPerMinute);
+        string wpmArg = wpm.ToString(CultureInfo.InvariantCulture);
+        // spd-say's rate is -100..100 around the voice default (about 180 wpm); SAPI's is -10..10,
+        // about 20 wpm a step.
+        int spdRate = Math.Clamp((wpm - DefaultWordsPerMinute) / 2, -100, 100);
+        int sapiRate = Math.Clamp((wpm - DefaultWordsPerMinute) / 20, -10, 10);
+        return backend switch
+        {
+            Backend.EspeakNg => new SpeechCommand("espeak-ng", ["-s", wpmArg, "--stdin"], text),
+            Backend.Espeak => new SpeechCommand("espeak", ["-s", wpmArg, "--stdin"], text),
+            Backend.SpdSay => new SpeechCommand("spd-say",
+                ["-r", spdRate.ToString(CultureInfo.InvariantCulture), "--", text], null),
+            Backend.Say => new SpeechCommand("say", ["-r", wpmArg, "-f", "-"], text),
+            Backend.WindowsSapi => new SpeechCommand("powershell",
+                ["-NoProfile", "-NonInteractive", "-Command",
+                    "Add-Type -AssemblyName System.Speech; "
+                    + "$s = New-Object System.Speech.Synthesis.SpeechSynthesizer; "
+                    + $"$s.Rate = {sapiRate.ToString(CultureInfo.InvariantCulture)}; "
+                    + "$s.Speak([Console]::In.ReadToEnd())"],
+                text),
+            _ => null,
+        };
+    }
+
+    private static Backend Detect()
+    {
+        if (RuntimeInformation.IsOSPlatform(OSPlatform.Windows)) return Backend.WindowsSapi;
+        if (RuntimeInformation.IsOSPlatform(OSPlatform.OSX))
+            return IsOnPath("say") ? Backend.Say : Backend.None;
+        if (IsOnPath("espeak-ng")) return Backend.EspeakNg;
+        if (IsOnPath("espeak")) return Backend.Espeak;
+        if (IsOnPath("spd-say")) return Backend.SpdSay;
+        return Backend.None;
+    }
+
+    private static bool IsOnPath(string executable)
+    {
+        var path = Environment.GetEnvironmentVariable("PATH");
+        if (string.IsNullOrEmpty(path)) return false;
+        foreach (var dir in path.Split(Path.PathSeparator, StringSplitOptions.RemoveEmptyEntries))
+            if (File.Exists(Path.Combine(dir, executable))) return true;
+        return false;
+    }
+
+    /// <summary>Run a helper command, waiting up to <paramref name="timeout"/> for it to finish.</summary>
+    private void TryRun(string fileName, TimeSpan timeout, params string[] args)
+    {
+        try
+        {
+            var psi = new ProcessStartInfo(fileName) { UseShellExecute = false, CreateNoWindow = true };
+            foreach (var arg in args) psi.ArgumentList.Add(arg);
+            using var process = Process.Start(psi);
+            if (process is not null && !process.WaitForExit(timeout))
+                _logger.Debug($"[Speech] {fileName} still running after {timeout.TotalMilliseconds:F0} ms");
+        }
+        catch (Exception ex)
+        {
+            _logger.Debug($"[Speech] {fileName} failed: {ex.Message}");
+        }
+    }
+}
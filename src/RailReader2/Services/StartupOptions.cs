@@ -0,0 +1,93 @@
+using System.Globalization;
+
+namespace RailReader2.Services;
+
+/// <summary>
+/// The desktop app's command line: an optional PDF plus known-state flags for scripted launches
+/// (<c>--page</c>, <c>--zoom</c>, <c>--rail</c>, <c>--fit-width</c>), and <c>--help</c>/<c>--version</c>.
+/// Problems don't stop the launch — they are collected in <see cref="Errors"/> and reported in the
+/// window; the offending flag is dropped.
+/// </summary>
+internal sealed record StartupOptions
+{
+    public IReadOnlyList<string> Files { get; private init; } = [];
+    /// <summary>1-based page to open at.</summary>
+    public int? Page { get; private init; }
+    /// <summary>Zoom percentage, e.g. 250 for 250%.</summary>
+    public double? ZoomPercent { get; private init; }
+    public bool Rail { get; private init; }
+    public bool FitWidth { get; private init; }
+    public bool Help { get; private init; }
+    public bool Version { get; private init; }
+    public IReadOnlyList<string> Errors { get; private init; } = [];
+
+    /// <summary>True when any flag asks for a particular initial view.</summary>
+    public bool HasViewRequest => Page is not null || ZoomPercent is not null || Rail || FitWidth;
+
+    public const string HelpText = """
+        Usage: railreader2 [options] [file.pdf]
+
+        Options:
+          --page <n>      Open at page n (1-based)
+          --zoom <pct>    Open at this zoom, in percent (e.g. 250)
+          --fit-width     Open fitted to the page width (kept while turning pages)
+          --rail          Start rail reading once the page is analysed
+          --help, -h      Show this help and exit
+          --version       Show the version and exit
+
+        To render pages to images without opening a window, use
+        railreader2-cli render (see railreader2-cli render --help).
+        """;
+
+    public static StartupOptions Parse(IReadOnlyList<string> args)
+    {
+        var files = new List<string>();
+        var errors = new List<string>();
+        int? page = null;
+        double? zoom = null;
+        bool rail = false, fitWidth = false, help = false, version = false;
+
+        for (int i = 0; i < args.Count; i++)
+        {
+            string arg = args[i];
+            string? Value() => i + 1 < args.Count && !args[i + 1].StartsWith("--") ? args[++i] : null;
+            switch (arg)
+            {
+                case "--page":
+                    if (Value() is { } p && int.TryParse(p, NumberStyles.Integer, CultureInfo.InvariantCulture, out int n) && n >= 1)
+                        page = n;
+                    else
+                        errors.Add("--page expects a page number (1 or more)");
+                    break;
+                case "--zoom":
+                    if (Value() is { } z && double.TryParse(z.TrimEnd('%'), NumberStyles.Float, CultureInfo.InvariantCulture, out double pct) && pct > 0)
+                        zoom = pct;
+                    else
+                        errors.Add("--zoom expects a percentage, e.g. --zoom 250");
+                    break;
+                case "--rail": rail = true; break;
+                case "--fit-width": fitWidth = true; break;
+                case "--help" or "-h": help = true; break;
+                case "--version": version = true; break;
+                default:
+                    if (arg.StartsWith('-') && arg.Length > 1)
+                        errors.Add($"Unknown option {arg}");
+                    else
+                        files.Add(arg);
+                    break;
+            }
+        }
+
+        if (zoom is not null && fitWidth)
+        {
+            errors.Add("--zoom and --fit-width can't be combined; using --fit-width");
+            zoom = null;
+        }
+
+        return new StartupOptions
+        {
+            Files = files, Page = page, ZoomPercent = zoom, Rail = rail, FitWidth = fitWidth,
+            Help = help, Version = version, Errors = errors,
+        };
+    }
+}
@@ -0,0 +1,25 @@
+using RailReader.Core.Models;
+
+namespace RailReader2.ViewModels;
+
+/// <summary>
+/// Undoable addition of an annotation made outside Core's tools (a rail-mode highlight).
+/// Defined consumer-side like <see cref="ChangeReviewStateAction"/>; unlike that one it
+/// edits the <see cref="AnnotationFile"/> it is handed, adding to / removing from the page's list.
+/// </summary>
+public sealed class AddAnnotationAction(int page, Annotation annotation) : IUndoAction
+{
+    public void Undo(AnnotationFile file)
+    {
+        if (file.Pages.TryGetValue(page, out var list))
+            list.Remove(annotation);
+    }
+
+    public void Redo(AnnotationFile file)
+    {
+        if (!file.Pages.TryGetValue(page, out var list))
+            file.Pages[page] = list = [];
+        if (!list.Contains(annotation))
+            list.Add(annotation);
+    }
+}
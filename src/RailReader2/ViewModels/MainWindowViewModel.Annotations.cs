@@ -299,6 +299,7 @@ public sealed partial class MainWindowViewModel
         catch (Exception ex)
         {
             _logger.Error("[Export] Failed", ex);
+            ShowErrorWithLog("Failed to export the annotated PDF");
         }
     }
 
@@ -327,6 +328,7 @@ public sealed partial class MainWindowViewModel
         catch (Exception ex)
         {
             _logger.Error("[Export JSON] Failed", ex);
+            ShowErrorWithLog("Failed to export annotations");
         }
     }
 
@@ -351,7 +353,7 @@ public sealed partial class MainWindowViewModel
             var imported = AnnotationService.ImportJson(inputPath);
             if (imported is null)
             {
-                ShowStatusToast("Failed to read annotation file");
+                ShowError($"Couldn't read annotations from {Path.GetFileName(inputPath)}");
                 return;
             }
 
@@ -369,7 +371,7 @@ public sealed partial class MainWindowViewModel
         catch (Exception ex)
         {
             _logger.Error("[Import JSON] Failed", ex);
-            ShowStatusToast("Failed to import annotations");
+            ShowErrorWithLog("Failed to import annotations");
         }
     }
 
@@ -412,6 +414,7 @@ public sealed partial class MainWindowViewModel
         catch (Exception ex)
         {
             _logger.Error("[Export Log] Failed", ex);
+            ShowError($"Failed to save the log: {ex.Message}");
         }
     }
 }
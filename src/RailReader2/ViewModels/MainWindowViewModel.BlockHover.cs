@@ -0,0 +1,44 @@
+using RailReader.Core;
+using RailReader.Core.Models;
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Block hover info: while a view is rail-reading, hovering a detected block shows a tooltip with the
+// model's class, confidence, reading order, and line count (BlockHoverInfo) — a lighter look at what
+// the detector saw than the full debug overlay. Settings → Rail Reading → Visual switches it off.
+public sealed partial class MainWindowViewModel
+{
+    // The loaded model's class table, indexed by LayoutBlock.ClassId; null without a model.
+    private IReadOnlyList<string>? _layoutClassNames;
+
+    /// <summary>Show block info on hover in rail mode. Persisted app-wide (shell sidecar).</summary>
+    public bool BlockHoverInfoEnabled
+    {
+        get => _shellPrefs.BlockHoverInfo;
+        set
+        {
+            if (_shellPrefs.BlockHoverInfo == value) return;
+            _shellPrefs.BlockHoverInfo = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(BlockHoverInfoEnabled));
+        }
+    }
+
+    /// <summary>The block under a page point of <paramref name="vp"/> and its tooltip text, or null when
+    /// the feature is off, the view isn't rail-reading, or nothing detected is there. Takes the hovered
+    /// view's own viewport, which need not be the focused one.</summary>
+    internal (LayoutBlock Block, string Text)? DescribeBlockAt(Viewport vp, double pageX, double pageY)
+    {
+        if (!BlockHoverInfoEnabled || !vp.Rail.Active || vp.Owner is not { } doc
+            || !doc.TryGetAnalysis(vp.CurrentPage, vp.AnalysisParams, out var analysis))
+            return null;
+        foreach (var b in analysis.Blocks)
+        {
+            if (pageX >= b.BBox.X && pageX <= b.BBox.X + b.BBox.W
+                && pageY >= b.BBox.Y && pageY <= b.BBox.Y + b.BBox.H)
+                return (b, BlockHoverInfo.Describe(b, _layoutClassNames));
+        }
+        return null;
+    }
+}
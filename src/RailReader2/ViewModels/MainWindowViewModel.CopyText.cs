@@ -0,0 +1,44 @@
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Copy rail text: Ctrl+C copies the current rail line (when no text is selected), Ctrl+Shift+C the
+// whole current block, as plain text read from the page's extraction layer.
+public sealed partial class MainWindowViewModel
+{
+    /// <summary>Ctrl+C: a text selection wins; otherwise, while rail-reading, the current line.</summary>
+    public void CopySelectionOrLine()
+    {
+        if (SelectedText is not null) CopySelectedText();
+        else if (_controller.FocusedViewport is { Rail.Active: true }) CopyCurrentLineText();
+    }
+
+    public void CopyCurrentLineText()
+    {
+        if (CurrentRailBlockOrToast() is not ({ } doc, { } vp, { } block)) return;
+        if (block.Lines.Count == 0)
+        {
+            CopyRailText(RailText.BlockText(doc.GetOrExtractText(vp.CurrentPage), block));
+            return;
+        }
+        int line = Math.Clamp(vp.Rail.CurrentLine, 0, block.Lines.Count - 1);
+        CopyRailText(RailText.LineText(doc.GetOrExtractText(vp.CurrentPage), block.Lines[line]));
+    }
+
+    public void CopyCurrentBlockText()
+    {
+        if (CurrentRailBlockOrToast() is not ({ } doc, { } vp, { } block)) return;
+        CopyRailText(RailText.BlockText(doc.GetOrExtractText(vp.CurrentPage), block));
+    }
+
+    private void CopyRailText(string? text)
+    {
+        if (string.IsNullOrEmpty(text))
+        {
+            ShowStatusToast("No text here to copy");
+            return;
+        }
+        CopyToClipboard?.Invoke(text);
+        ShowStatusToast($"Copied {text.Length} character{(text.Length == 1 ? "" : "s")}");
+    }
+}
@@ -10,7 +10,7 @@ namespace RailReader2.ViewModels;
 // Document management: open, close, tabs, sidebar state
 public sealed partial class MainWindowViewModel
 {
-    private int? _pendingDuplicatePage;
+    private Viewport? _pendingDuplicateSource;
 
     /// <summary>Save sidebar visibility and width to the given tab.</summary>
     private void SaveSidebarState(TabViewModel tab)
@@ -29,10 +29,10 @@ public sealed partial class MainWindowViewModel
 
     public async Task OpenDocument(string path)
     {
-        // Consume the duplicate-source page up front so it can never leak into a later, unrelated
+        // Consume the duplicate-source view up front so it can never leak into a later, unrelated
         // open (e.g. if this call early-returns below). Only the duplicate-tab dedup path uses it.
-        int? duplicatePage = _pendingDuplicatePage;
-        _pendingDuplicatePage = null;
+        var duplicateSource = _pendingDuplicateSource;
+        _pendingDuplicateSource = null;
 
         if (IsScanAllActive) return;
 
@@ -45,7 +45,7 @@ public sealed partial class MainWindowViewModel
         {
             if (string.Equals(Path.GetFullPath(t.FilePath), full, StringComparison.Ordinal))
             {
-                OpenSharedViewportTab(t, duplicatePage);
+                OpenSharedViewportTab(t, duplicateSource);
                 return;
             }
         }
@@ -53,78 +53,101 @@ public sealed partial class MainWindowViewModel
         try
         {
             _logger.Debug($"[OpenDocument] Opening: {path}");
+            if (await LoadDocumentModel(path, knownPassword: null) is not { } state) return;
+            AddDocumentTab(state);
+            _logger.Debug("[OpenDocument] Tab added successfully");
+        }
+        catch (Exception ex)
+        {
+            _logger.Error($"Failed to open {path}", ex);
+            ShowErrorWithLog($"Failed to open {Path.GetFileName(path)}");
+        }
+    }
 
-            // Encrypted PDFs throw PdfPasswordRequiredException from CreateDocument (on the
-            // background thread). Dialogs must be shown on the UI thread, so the open attempt
-            // lives in Task.Run while the password *resolution* loops out here. The resolved
-            // password is held only inside the opened IPdfService — never persisted, and never
-            // passed again after CreateDocument (LoadAnnotations etc. read IPdfService.Password).
-            TabViewModel? tab = null;
-            string? password = null;
-            while (tab is null)
+    /// <summary>Open <paramref name="path"/> as a new <see cref="DocumentModel"/> and render its first
+    /// page, without touching the tabs — so a reload can check the new copy before giving up the old.
+    /// <paramref name="knownPassword"/> (a reload's current one) is tried before prompting. Null when
+    /// the password prompt is cancelled; throws when the file can't be opened.</summary>
+    private async Task<DocumentModel?> LoadDocumentModel(string path, string? knownPassword)
+    {
+        // Encrypted PDFs throw PdfPasswordRequiredException from CreateDocument (on the
+        // background thread). Dialogs must be shown on the UI thread, so the open attempt
+        // lives in Task.Run while the password *resolution* loops out here. The resolved
+        // password is held only inside the opened IPdfService — never persisted, and never
+        // passed again after CreateDocument (LoadAnnotations etc. read IPdfService.Password).
+        string? password = knownPassword;
+        while (true)
+        {
+            var attemptPassword = password;
+            DocumentModel? state = null;
+            try
             {
-                var attemptPassword = password;
-                DocumentModel? state = null;
-                try
+                await Task.Run(() =>
                 {
-                    await Task.Run(() =>
-                    {
-                        state = _controller.CreateDocument(path, attemptPassword);
-                        if (!state.LoadPageBitmap())
-                            throw new InvalidOperationException($"Failed to render first page of {Path.GetFileName(path)}");
-                    });
-                }
-                catch (PdfPasswordRequiredException ex)
-                {
-                    if (_window is null) return;
-                    var entered = await new PasswordDialog(Path.GetFileName(path), ex.WrongPassword)
-                        .ShowDialog<string?>(_window);
-                    // Cancel = "changed my mind", not a failure — abort quietly (no toast).
-                    if (entered is null) return;
-                    password = entered;
-                    continue;
-                }
-
-                tab = new TabViewModel(state!, state!.Primary);
+                    state = _controller.CreateDocument(path, attemptPassword);
+                    if (!state.LoadPageBitmap())
+                        throw new InvalidOperationException($"Failed to render first page of {Path.GetFileName(path)}");
+                });
+                return state;
+            }
+            catch (PdfPasswordRequiredException ex)
+            {
+                if (_window is null) return null;
+                var entered = await new PasswordDialog(Path.GetFileName(path), ex.WrongPassword)
+                    .ShowDialog<string?>(_window);
+                // Cancel = "changed my mind", not a failure — abort quietly (no toast).
+                if (entered is null) return null;
+                password = entered;
+            }
+            catch
+            {
+                state?.Dispose(); // opened, but the first page wouldn't render
+                throw;
             }
+        }
+    }
 
-            _logger.Debug($"[OpenDocument] Loaded: {tab.PageCount} pages, {tab.PageWidth}x{tab.PageHeight}");
-            tab.LoadAnnotations(_controller.AnnotationManager);
-            // Linked-context portals (shell sidecar, keyed by PDF SHA-256). One reference-counted set is
-            // shared across all tabs/panes of the same PDF, so saves from duplicate tabs don't clobber
-            // each other (released in CloseTab).
-            tab.Portals = Services.PortalSetManager.Default.Checkout(tab.FilePath);
+    /// <summary>A tab over a freshly loaded <paramref name="state"/>'s primary view, with its
+    /// annotations and portals loaded; not yet added to <see cref="Tabs"/>.</summary>
+    private TabViewModel CreateDocumentTab(DocumentModel state)
+    {
+        var tab = new TabViewModel(state, state.Primary);
+        _logger.Debug($"[OpenDocument] Loaded: {tab.PageCount} pages, {tab.PageWidth}x{tab.PageHeight}");
+        tab.LoadAnnotations(_controller.AnnotationManager);
+        // Linked-context portals (shell sidecar, keyed by PDF SHA-256). One reference-counted set is
+        // shared across all tabs/panes of the same PDF, so saves from duplicate tabs don't clobber
+        // each other (released in CloseTab).
+        tab.Portals = Services.PortalSetManager.Default.Checkout(tab.FilePath);
+        return tab;
+    }
 
-            // Save sidebar state from outgoing tab before switching
-            if (ActiveTab is { } oldTab)
-                SaveSidebarState(oldTab);
+    /// <summary>Add a tab for a freshly loaded <paramref name="state"/> and make it active.</summary>
+    private void AddDocumentTab(DocumentModel state)
+    {
+        var tab = CreateDocumentTab(state);
 
-            _controller.AddDocument(tab.State);
-            Tabs.Add(tab);
+        // Save sidebar state from outgoing tab before switching
+        if (ActiveTab is { } oldTab)
+            SaveSidebarState(oldTab);
 
-            // New tab inherits the current sidebar state
-            tab.ShowSidePanel = ShowOutline;
-            if (ReadSidePanelWidth is { } getWidth)
-                tab.SidePanelWidth = getWidth();
+        _controller.AddDocument(tab.State);
+        Tabs.Add(tab);
 
-            ActiveTabIndex = Tabs.Count - 1;
-            OnPropertyChanged(nameof(ActiveTab));
-            // Route focus to this tab's own view (wires its reading-context signals + focus visuals).
-            FocusViewport(tab.Viewport);
+        // New tab inherits the current sidebar state
+        tab.ShowSidePanel = ShowOutline;
+        if (ReadSidePanelWidth is { } getWidth)
+            tab.SidePanelWidth = getWidth();
 
-            InvalidateAll();
+        ActiveTabIndex = Tabs.Count - 1;
+        OnPropertyChanged(nameof(ActiveTab));
+        // Route focus to this tab's own view (wires its reading-context signals + focus visuals).
+        FocusViewport(tab.Viewport);
 
-            Dispatcher.UIThread.Post(() => InvalidatePage(), DispatcherPriority.Background);
-            RequestAnimationFrame();
-            StartBackgroundAnalysis();
+        InvalidateAll();
 
-            _logger.Debug("[OpenDocument] Tab added successfully");
-        }
-        catch (Exception ex)
-        {
-            _logger.Error($"Failed to open {path}", ex);
-            ShowStatusToast($"Failed to open: {Path.GetFileName(path)}");
-        }
+        Dispatcher.UIThread.Post(() => InvalidatePage(), DispatcherPriority.Background);
+        RequestAnimationFrame();
+        StartBackgroundAnalysis();
     }
 
     /// <summary>Open a new tab that shares <paramref name="existing"/>'s <see cref="DocumentModel"/>
@@ -132,11 +155,28 @@ public sealed partial class MainWindowViewModel
     /// duplicate-source page (or the existing tab's current page), seeded + sized like a split pane so
     /// its rail seats and it centres correctly when shown. Caches + annotations are shared with the
     /// existing tab; the new tab navigates independently.</summary>
-    private void OpenSharedViewportTab(TabViewModel existing, int? duplicatePage)
+    private void OpenSharedViewportTab(TabViewModel existing, Viewport? cloneFrom)
+    {
+        var tab = CreateSharedViewportTab(existing.State, cloneFrom?.CurrentPage ?? existing.CurrentPage, cloneFrom);
+
+        if (ActiveTab is { } oldTab) SaveSidebarState(oldTab);
+        tab.ShowSidePanel = ShowOutline;
+        if (ReadSidePanelWidth is { } getWidth) tab.SidePanelWidth = getWidth();
+
+        Tabs.Add(tab);
+        ActiveTabIndex = Tabs.Count - 1;
+        OnPropertyChanged(nameof(ActiveTab));
+        FocusViewport(tab.Viewport);
+        InvalidateAll();
+        RequestAnimationFrame();
+        StartBackgroundAnalysis();
+    }
+
+    /// <summary>The viewport-and-tab half of <see cref="OpenSharedViewportTab"/>: a new view on
+    /// <paramref name="model"/> at <paramref name="page"/>, not yet added to <see cref="Tabs"/>.</summary>
+    private TabViewModel CreateSharedViewportTab(DocumentModel model, int page, Viewport? cloneFrom)
     {
-        var model = existing.State;
         var vp = model.AddViewport();
-        int page = duplicatePage ?? existing.CurrentPage;
         vp.CurrentPage = Math.Clamp(page, 0, model.PageCount - 1);
         vp.IsLive = true;
 
@@ -145,7 +185,20 @@ public sealed partial class MainWindowViewModel
         var (w, h) = FocusedViewportSize();
         if (w > 0 && h > 0) vp.SetSize(w, h);
         vp.LoadPageBitmap();
-        vp.CenterPage(vp.Width, vp.Height);
+        if (cloneFrom is not null && cloneFrom.CurrentPage == vp.CurrentPage)
+        {
+            // Duplicate Tab: land on exactly the source's view (zoom + pan), so rail mode re-engages
+            // at the same zoom instead of resetting to a centred fit. The clamp guards a source pane
+            // whose surface is a different size than this one.
+            vp.Camera.Zoom = cloneFrom.Camera.Zoom;
+            vp.Camera.OffsetX = cloneFrom.Camera.OffsetX;
+            vp.Camera.OffsetY = cloneFrom.Camera.OffsetY;
+            vp.ClampCamera(vp.Width, vp.Height);
+        }
+        else
+        {
+            vp.CenterPage(vp.Width, vp.Height);
+        }
         vp.UpdateRailZoom(vp.Width, vp.Height);
         // Seat this view's rail: a cache hit (the page the existing view already analysed) seats
         // synchronously; otherwise analysis is scheduled and the fan-out seats it on arrival.
@@ -154,19 +207,7 @@ public sealed partial class MainWindowViewModel
 
         // Shares the model (PDF/caches/annotations already loaded). Portals are shared too via the
         // reference-counted manager, so this tab sees and saves the same set as its sibling.
-        var tab = new TabViewModel(model, vp) { Portals = Services.PortalSetManager.Default.Checkout(model.FilePath) };
-
-        if (ActiveTab is { } oldTab) SaveSidebarState(oldTab);
-        tab.ShowSidePanel = ShowOutline;
-        if (ReadSidePanelWidth is { } getWidth) tab.SidePanelWidth = getWidth();
-
-        Tabs.Add(tab);
-        ActiveTabIndex = Tabs.Count - 1;
-        OnPropertyChanged(nameof(ActiveTab));
-        FocusViewport(vp);
-        InvalidateAll();
-        RequestAnimationFrame();
-        StartBackgroundAnalysis();
+        return new TabViewModel(model, vp) { Portals = Services.PortalSetManager.Default.Checkout(model.FilePath) };
     }
 
     [RelayCommand]
@@ -188,6 +229,22 @@ public sealed partial class MainWindowViewModel
         }
     }
 
+    /// <summary>Open files dropped onto the window, one tab each, in drop order — so the last one
+    /// ends up active. Anything that isn't a PDF is skipped with a toast.</summary>
+    public async Task OpenDroppedFiles(IReadOnlyList<string> paths)
+    {
+        var pdfs = paths
+            .Where(p => string.Equals(Path.GetExtension(p), ".pdf", StringComparison.OrdinalIgnoreCase))
+            .ToList();
+        int skipped = paths.Count - pdfs.Count;
+        if (skipped > 0)
+            ShowStatusToast(pdfs.Count == 0
+                ? "Only PDF files can be opened"
+                : $"Skipped {skipped} non-PDF file{(skipped == 1 ? "" : "s")}");
+        foreach (var path in pdfs)
+            await OpenDocument(path);
+    }
+
     [RelayCommand]
     public void CloseTab(int index)
     {
@@ -294,6 +351,7 @@ public sealed partial class MainWindowViewModel
             // Focusing fires neither PageChanged nor ReadingPositionChanged, so evaluate portals here —
             // otherwise the previous tab's target crop lingers on a quiescent switch.
             EvaluatePortals();
+            PublishFollowAlongPosition();
             InvalidateAll();
         }
     }
@@ -322,7 +380,7 @@ public sealed partial class MainWindowViewModel
     {
         if (ActiveTab is { } tab)
         {
-            _pendingDuplicatePage = tab.CurrentPage;
+            _pendingDuplicateSource = tab.Viewport;
             await OpenDocument(tab.FilePath);
         }
     }
@@ -0,0 +1,113 @@
+using Avalonia.Platform.Storage;
+using CommunityToolkit.Mvvm.Input;
+using RailReader.Core.Models;
+using RailReader.Renderer.Skia;
+using RailReader2.Services;
+using RailReader2.Views;
+
+namespace RailReader2.ViewModels;
+
+// Page images: File → Export Pages as Images renders the current page or a range to PNG on a
+// background thread, optionally with the active colour effect applied. The export opens its own copy
+// of the PDF: the tab's handle belongs to the UI thread (PDFium isn't thread-safe) and may be disposed
+// by a close or reload while a long export is still running.
+public sealed partial class MainWindowViewModel
+{
+    private bool _exportingImages;
+
+    [RelayCommand]
+    public async Task ExportPagesAsImages()
+    {
+        if (_window is null || ActiveTab is not { } tab) return;
+        if (_exportingImages)
+        {
+            ShowStatusToast("An image export is already running");
+            return;
+        }
+
+        var effect = _controller.ActiveColourEffect;
+        var options = await new ExportImagesDialog(tab.CurrentPage + 1, tab.PageCount, effect != ColourEffect.None)
+            .ShowDialog<ExportImagesOptions?>(_window);
+        if (options is null) return;
+
+        // One page: pick the file. Several: pick a folder and number the files after the document.
+        Func<int, string> outputPathFor;
+        string destination;
+        if (options.Pages.Count == 1)
+        {
+            var file = await _window.StorageProvider.SaveFilePickerAsync(new FilePickerSaveOptions
+            {
+                Title = "Export Page as Image",
+                DefaultExtension = "png",
+                FileTypeChoices = [new FilePickerFileType("PNG Images") { Patterns = ["*.png"] }],
+                SuggestedFileName = PageImageExporter.FileNameFor(tab.FilePath, options.Pages[0], tab.PageCount),
+            });
+            var outputPath = file?.TryGetLocalPath() ?? file?.Path.LocalPath;
+            if (outputPath is null) return;
+            outputPathFor = _ => outputPath;
+            destination = Path.GetFileName(outputPath);
+        }
+        else
+        {
+            // The file picker asks before replacing a file; a folder picker doesn't, so ask here, once.
+            string dir;
+            while (true)
+            {
+                var folders = await _window.StorageProvider.OpenFolderPickerAsync(new FolderPickerOpenOptions
+                {
+                    Title = $"Export {options.Pages.Count} Pages as Images",
+                });
+                if ((folders.Count > 0 ? folders[0].TryGetLocalPath() : null) is not { } picked) return;
+                dir = picked;
+                var existing = PageImageExporter.ExistingOutputs(dir, tab.FilePath, options.Pages, tab.PageCount);
+                if (existing.Count == 0) break;
+                bool? overwrite = await new OverwriteImagesDialog(existing, dir).ShowDialog<bool?>(_window);
+                if (overwrite is null) return;
+                if (overwrite == true) break;
+            }
+            outputPathFor = page => Path.Combine(dir, PageImageExporter.FileNameFor(tab.FilePath, page, tab.PageCount));
+            destination = dir;
+        }
+
+        var path = tab.FilePath;
+        string? password = string.IsNullOrEmpty(tab.Pdf.Password) ? null : tab.Pdf.Password;
+        int total = options.Pages.Count;
+        var intensity = (float)_controller.ActiveColourIntensity;
+        var progress = total == 1 ? null : new Progress<int>(done =>
+        {
+            // Progress<T> posts back to the UI thread; toast every tenth page to avoid flicker.
+            if (done < total && done % 10 == 0) ShowStatusToast($"Exporting images… {done} of {total}");
+        });
+
+        _exportingImages = true;
+        ShowStatusToast(total == 1 ? "Exporting image…" : $"Exporting {total} images…");
+        try
+        {
+            var (written, failed) = await Task.Run(() =>
+            {
+                var pdf = new SkiaPdfServiceFactory().CreatePdfService(path, password);
+                try
+                {
+                    return PageImageExporter.Export(pdf, options.Pages, options.Dpi,
+                        options.ApplyColourEffect ? effect : ColourEffect.None, intensity, outputPathFor, progress, _logger);
+                }
+                finally
+                {
+                    (pdf as IDisposable)?.Dispose();
+                }
+            });
+            ShowStatusToast(failed == 0
+                ? $"Exported {written} image{(written == 1 ? "" : "s")} to {destination}"
+                : $"Exported {written} of {total} images to {destination} — {failed} failed (see log)");
+        }
+        catch (Exception ex)
+        {
+            _logger.Error("[ExportImages] Failed", ex);
+            ShowErrorWithLog("Failed to export images");
+        }
+        finally
+        {
+            _exportingImages = false;
+        }
+    }
+}
@@ -0,0 +1,40 @@
+using RailReader.Core;
+
+namespace RailReader2.ViewModels;
+
+/// <summary>Which fit a tab keeps re-applying: none (the zoom was set by hand), the whole page, or the
+/// page width.</summary>
+public enum FitMode { Manual, FitPage, FitWidth }
+
+// Sticky fit: View → Fit Page / Fit Width (0, Ctrl+2) set the active tab's FitMode, and the tab then
+// re-fits whenever it lands on a different page or its view is resized, so pages of differing widths
+// and window resizes don't leave a stale zoom. Any manual zoom drops back to Manual. Rail mode owns the
+// camera while active, so no re-fit happens then — a fit-width zoom that crosses the rail threshold on
+// a narrow page is allowed and simply hands over to rail.
+public sealed partial class MainWindowViewModel
+{
+    private void SetFitMode(FitMode mode)
+    {
+        // Only the tab's own view is tracked; a split pane or tear-off keeps its zoom as set.
+        if (ActiveTab is not { } tab || _controller.FocusedViewport != tab.Viewport) return;
+        tab.FitMode = mode;
+        tab.FitModePage = tab.Viewport.CurrentPage;
+    }
+
+    /// <summary>Re-apply the active tab's fit after navigation, if it moved to another page.</summary>
+    private void ReapplyFitModeOnPageChange()
+    {
+        if (ActiveTab is { } tab && tab.FitModePage != tab.Viewport.CurrentPage)
+            ReapplyFitMode(tab.Viewport);
+    }
+
+    /// <summary>Re-apply the fit of the tab viewing <paramref name="vp"/>, when that view is focused
+    /// (Core's fits act on the focused viewport) and not rail-reading or frozen.</summary>
+    internal void ReapplyFitMode(Viewport vp)
+    {
+        if (ActiveTab is not { FitMode: not FitMode.Manual } tab || tab.Viewport != vp) return;
+        if (_controller.FocusedViewport != vp || vp.Rail.Active || IsViewportFrozen(vp)) return;
+        tab.FitModePage = vp.CurrentPage;
+        Dispatch(tab.FitMode == FitMode.FitWidth ? _controller.FitWidth : _controller.FitPage, InvalidateCameraAndTab);
+    }
+}
@@ -0,0 +1,80 @@
+using Avalonia.Threading;
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Follow-along IPC: an optional local server that reports the rail position to an external companion
+// (screen reader / OCR tool) and lets it move the rail. Off by default; toggled in Settings → Advanced.
+public sealed partial class MainWindowViewModel
+{
+    private readonly ShellPreferences _shellPrefs = ShellPreferences.Load();
+    private FollowAlongServer? _followAlong;
+
+    /// <summary>Run the follow-along server. Persisted app-wide (shell sidecar); takes effect
+    /// immediately.</summary>
+    public bool FollowAlongEnabled
+    {
+        get => _shellPrefs.FollowAlongEnabled;
+        set
+        {
+            if (_shellPrefs.FollowAlongEnabled == value) return;
+            _shellPrefs.FollowAlongEnabled = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(FollowAlongEnabled));
+            ApplyFollowAlongServer();
+        }
+    }
+
+    /// <summary>Start or stop the server to match <see cref="FollowAlongEnabled"/>. Idempotent.</summary>
+    private void ApplyFollowAlongServer()
+    {
+        if (FollowAlongEnabled == (_followAlong is not null)) return;
+
+        if (_followAlong is { } server)
+        {
+            server.Dispose();
+            _followAlong = null;
+            return;
+        }
+
+        // Requests arrive on the server's thread; everything they touch is UI-thread state.
+        _followAlong = new FollowAlongServer(FollowAlongServer.DefaultPipeName,
+            request => Dispatcher.UIThread.Post(() => HandleFollowAlongGoto(request)), _logger);
+        _followAlong.Start();
+        PublishFollowAlongPosition();
+    }
+
+    /// <summary>Report the focused view's position: page/block/line + line text while rail-reading,
+    /// just the page otherwise. Called from <see cref="OnReadingContextChanged"/>; the server drops
+    /// repeats, so calling it on every context change is cheap.</summary>
+    private void PublishFollowAlongPosition()
+    {
+        if (_followAlong is not { } server) return;
+        if (GetReadingPosition() is { } pos)
+            server.PublishPosition(FollowAlongProtocol.FormatPosition(pos.Page, pos.BlockIndex, pos.LineIndex, pos.LineText));
+        else if (_controller.FocusedViewport is { } vp)
+            server.PublishPosition(FollowAlongProtocol.FormatPage(vp.CurrentPage));
+    }
+
+    /// <summary>Apply a validated goto request: go to the page, then (if given) frame the block and
+    /// seat the rail on the line — the same path the portal "go to source" jump takes. Out-of-range
+    /// targets are answered with an error rather than clamped, so the client learns it is out of sync.</summary>
+    private void HandleFollowAlongGoto(FollowAlongProtocol.GotoRequest request)
+    {
+        if (_followAlong is not { } server) return;
+        if (IsScanAllActive || ActiveTab is not { } tab)
+        {
+            server.Send(FollowAlongProtocol.FormatError("no document ready"));
+            return;
+        }
+        if (request.Page >= tab.PageCount)
+        {
+            server.Send(FollowAlongProtocol.FormatError($"page out of range (document has {tab.PageCount})"));
+            return;
+        }
+
+        GoToPage(request.Page);
+        if (request.Block is { } block && !SmoothlyFrameBlock(block, line: request.Line ?? 0))
+            server.Send(FollowAlongProtocol.FormatError("block not available (page not analysed yet, or out of range)"));
+    }
+}
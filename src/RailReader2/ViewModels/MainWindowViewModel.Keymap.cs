@@ -0,0 +1,50 @@
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Key bindings: user chords from shell_prefs.json's key_bindings, translated to the built-in keys by
+// the window before its own key handling. Built at startup, where problems are logged and toasted,
+// and rebuilt whenever Settings → Shortcuts changes a binding.
+public sealed partial class MainWindowViewModel
+{
+    private Keymap? _keymap;
+
+    internal Keymap Keymap
+    {
+        get
+        {
+            if (_keymap is not null) return _keymap;
+            _keymap = Keymap.Build(_shellPrefs.KeyBindings);
+            foreach (var problem in _keymap.Problems)
+                _logger.Info($"[Keymap] {problem}");
+            if (_keymap.Problems.Count > 0)
+                ShowStatusToast($"Key bindings: {_keymap.Problems[0]}"
+                    + (_keymap.Problems.Count > 1 ? $" (+{_keymap.Problems.Count - 1} more in the log)" : ""));
+            return _keymap;
+        }
+    }
+
+    /// <summary>The saved bindings, action name → chord, as written in shell_prefs.json.</summary>
+    internal IReadOnlyDictionary<string, string> KeyBindings => _shellPrefs.KeyBindings;
+
+    /// <summary>Bind <paramref name="action"/> to <paramref name="chord"/>, or back to its default
+    /// when null, save, and put the new keymap into effect. The caller has vetted the chord.</summary>
+    internal void SetKeyBinding(string action, string? chord)
+    {
+        foreach (var key in _shellPrefs.KeyBindings.Keys
+                     .Where(k => string.Equals(k, action, StringComparison.OrdinalIgnoreCase)).ToList())
+            _shellPrefs.KeyBindings.Remove(key);
+        if (chord is not null) _shellPrefs.KeyBindings[action] = chord;
+        _shellPrefs.Save();
+        _keymap = Keymap.Build(_shellPrefs.KeyBindings);
+    }
+
+    /// <summary>Drop every user binding, save, and go back to the built-in keys.</summary>
+    internal void ResetKeyBindings()
+    {
+        if (_shellPrefs.KeyBindings.Count == 0) return;
+        _shellPrefs.KeyBindings.Clear();
+        _shellPrefs.Save();
+        _keymap = Keymap.Build(_shellPrefs.KeyBindings);
+    }
+}
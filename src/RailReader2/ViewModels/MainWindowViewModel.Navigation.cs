@@ -2,10 +2,11 @@ using Avalonia.Threading;
 using CommunityToolkit.Mvvm.Input;
 using RailReader.Core;
 using RailReader.Core.Models;
+using RailReader2.Services;
 
 namespace RailReader2.ViewModels;
 
-// Navigation, camera, rail, auto-scroll, line focus, colour effects
+// Navigation, camera, rail, auto-scroll, line focus, colour effects, reading progress
 public sealed partial class MainWindowViewModel
 {
     // --- Navigation ---
@@ -65,6 +66,7 @@ public sealed partial class MainWindowViewModel
     public void FitPage()
     {
         if (ZoomBlockedByFreeze()) return;
+        SetFitMode(FitMode.FitPage);
         Dispatch(_controller.FitPage, InvalidateCameraAndTab);
     }
 
@@ -72,14 +74,44 @@ public sealed partial class MainWindowViewModel
     public void FitWidth()
     {
         if (ZoomBlockedByFreeze()) return;
+        SetFitMode(FitMode.FitWidth);
         Dispatch(_controller.FitWidth, InvalidateCameraAndTab);
     }
 
+    // Share of the view width the block fills after Fit Block Width — leaves a small margin either side.
+    private const double FitBlockWidthFill = 0.9;
+
+    /// <summary>Zoom so the seated rail block fills the view width (a column of a two-column paper, a
+    /// narrow sidebar), keeping the rail on its current line. Framed through
+    /// <see cref="SmoothlyFrameBlock"/> with an explicit zoom, so the eased motion, freeze gating and
+    /// Core's zoom clamp all apply. Never zooms below the rail threshold: a very wide block would
+    /// otherwise drop the reader out of rail mode.</summary>
+    [RelayCommand]
+    public void FitBlockWidth()
+    {
+        if (CurrentRailBlockOrToast() is not ({ } doc, { } vp, { } block)) return;
+        if (block.BBox.W <= 0 || !doc.TryGetAnalysis(vp.CurrentPage, vp.AnalysisParams, out var analysis))
+            return;
+        int index = -1;
+        for (int i = 0; i < analysis.Blocks.Count; i++)
+            if (ReferenceEquals(analysis.Blocks[i], block)) { index = i; break; }
+        if (index < 0) return;
+
+        SetFitMode(FitMode.Manual);
+        var (ww, _) = FocusedViewportSize();
+        double zoom = Math.Clamp(ww * FitBlockWidthFill / block.BBox.W, 0.5, 20.0); // Core's zoom range
+        zoom = Math.Max(zoom, _appConfig.RailZoomThreshold);
+        SmoothlyFrameBlock(index, zoom, line: vp.Rail.CurrentLine);
+    }
+
     // --- Camera ---
 
     public void HandleZoom(double scrollDelta, double cursorX, double cursorY, bool ctrlHeld)
     {
         if (ZoomBlockedByFreeze()) return;
+        // With ctrlHeld Core scrolls along the rail line instead of zooming, so a sticky fit survives
+        // (this covers Ctrl+wheel, and Shift+wheel in rail mode when the wheel is set to scroll).
+        if (!ctrlHeld) SetFitMode(FitMode.Manual);
         Dispatch(() => _controller.HandleZoom(scrollDelta, cursorX, cursorY, ctrlHeld), InvalidateCameraAndTab, animate: true);
     }
 
@@ -89,6 +121,7 @@ public sealed partial class MainWindowViewModel
     public void HandleZoomKey(bool zoomIn)
     {
         if (ZoomBlockedByFreeze()) return;
+        SetFitMode(FitMode.Manual);
         Dispatch(() => _controller.HandleZoomKey(zoomIn), InvalidateCameraAndTab, animate: true);
     }
 
@@ -109,6 +142,7 @@ public sealed partial class MainWindowViewModel
         if (ActiveTab is not { } tab) return;
         double current = tab.Camera.Zoom;
         if (current <= 0) return;
+        SetFitMode(FitMode.Manual);
         double target = Math.Clamp(percent / 100.0, 0.5, 20.0); // mirrors Core's HandleZoom clamp
         double delta = ((target / current) - 1.0) / ZoomDeltaScale;
         var (ww, wh) = FocusedViewportSize();
@@ -192,17 +226,20 @@ public sealed partial class MainWindowViewModel
         ActivateRailAtClick(ww / 2.0, wh / 2.0);
     }
 
-    /// <summary>Apply a requested initial view from launch flags (<c>--page</c>/<c>--zoom</c>/<c>--rail</c>),
-    /// after the startup document has opened. Page is 1-based; zoom is a percentage (e.g. 300 = 300%).
+    /// <summary>Apply a requested initial view from launch flags (<c>--page</c>/<c>--zoom</c>/<c>--rail</c>/
+    /// <c>--fit-width</c>), after the startup document has opened. Page is 1-based; zoom is a percentage
+    /// (e.g. 300 = 300%); fit-width sets the tab's sticky fit, as View → Fit Width does.
     /// Rail engages once the page is analysed: a high enough zoom seats it via the tick, otherwise this
     /// forces it at the viewport centre (a short poll waits for analysis, then gives up — no model = no
     /// rail).</summary>
-    public void ApplyStartupView(int? page1Based, double? zoomPercent, bool rail)
+    public void ApplyStartupView(int? page1Based, double? zoomPercent, bool rail, bool fitWidth = false)
     {
         if (ActiveTab is not { } tab) return;
         if (page1Based is { } p)
             GoToPage(Math.Clamp(p - 1, 0, Math.Max(0, tab.PageCount - 1)));
-        if (zoomPercent is { } z)
+        if (fitWidth)
+            FitWidth();
+        else if (zoomPercent is { } z)
             SetZoomPercent(z);
         if (!rail) return;
 
@@ -288,7 +325,7 @@ public sealed partial class MainWindowViewModel
             catch (Exception ex)
             {
                 _logger.Error($"Failed to open URL: {uri}", ex);
-                ShowStatusToast("Failed to open link");
+                ShowError("Failed to open link — no browser responded");
             }
         }
     }
@@ -429,4 +466,33 @@ public sealed partial class MainWindowViewModel
         InvalidateOverlay();
         return effect;
     }
+
+    // --- Reading progress ---
+
+    /// <summary>The active tab's progress through its document (focused view's page + rail line) and
+    /// the estimated time left at the recent pace (null until there's enough reading to go on). Null
+    /// when the focused view isn't showing the active tab's document (e.g. a tear-off of another tab).</summary>
+    internal (double Fraction, TimeSpan? Remaining)? GetReadingProgress()
+    {
+        if (ActiveTab is not { } tab || ReadingProgressFraction(tab) is not { } fraction) return null;
+        return (fraction, tab.Progress.EstimateRemaining(fraction));
+    }
+
+    /// <summary>Feed the active tab's pace sample; called on every reading-context change.</summary>
+    private void RecordReadingProgress()
+    {
+        if (ActiveTab is { } tab && ReadingProgressFraction(tab) is { } fraction)
+            tab.Progress.Record(fraction, tab.PageCount);
+    }
+
+    private double? ReadingProgressFraction(TabViewModel tab)
+    {
+        var vp = _controller.FocusedViewport ?? tab.Viewport;
+        if (!ReferenceEquals(vp.Owner, tab.State)) return null;
+        var rail = vp.Rail;
+        return rail.Active
+            ? ReadingProgress.Fraction(vp.CurrentPage, tab.PageCount,
+                rail.CurrentBlock, rail.NavigableCount, rail.CurrentLine, rail.CurrentLineCount)
+            : ReadingProgress.Fraction(vp.CurrentPage, tab.PageCount, 0, 0, 0, 0);
+    }
 }
@@ -0,0 +1,58 @@
+namespace RailReader2.ViewModels;
+
+/// <summary>A failure the user should see: stays in the status bar until dismissed. The optional
+/// action (e.g. "Settings") is offered as a button next to the message.</summary>
+public sealed record ErrorNotice(string Message, string? ActionLabel = null, Action? Action = null);
+
+// Errors vs. status toasts: a toast (ShowStatusToast) is a passing confirmation and clears itself after a
+// few seconds; an error notice is kept until dismissed, since it usually reports something that didn't
+// happen. Notices queue — the status bar shows the oldest, with a count of the rest, and dismissing it
+// reveals the next. The caller still logs the exception; the notice is the user-facing half.
+public sealed partial class MainWindowViewModel
+{
+    private readonly List<ErrorNotice> _errorNotices = [];
+
+    // Enough for a burst of failures (a batch open of unreadable files) without growing unbounded.
+    private const int MaxErrorNotices = 20;
+
+    /// <summary>The notice the status bar shows, or null when there are none.</summary>
+    public ErrorNotice? ErrorNotice => _errorNotices.Count > 0 ? _errorNotices[0] : null;
+
+    /// <summary>Notices waiting behind <see cref="ErrorNotice"/>.</summary>
+    public int PendingErrorNotices => Math.Max(0, _errorNotices.Count - 1);
+
+    public void ShowError(string message, string? actionLabel = null, Action? action = null)
+    {
+        // The same failure repeated (e.g. retrying an export) shouldn't stack copies.
+        if (_errorNotices.Any(n => n.Message == message)) return;
+        if (_errorNotices.Count >= MaxErrorNotices) _errorNotices.RemoveAt(1);
+        _errorNotices.Add(new ErrorNotice(message, actionLabel, action));
+        OnErrorNoticesChanged();
+    }
+
+    public void DismissError()
+    {
+        if (_errorNotices.Count == 0) return;
+        _errorNotices.RemoveAt(0);
+        OnErrorNoticesChanged();
+    }
+
+    /// <summary>Run the shown notice's action, then dismiss it.</summary>
+    public void RunErrorAction()
+    {
+        if (ErrorNotice is not { Action: { } action }) return;
+        DismissError();
+        action();
+    }
+
+    private void OnErrorNoticesChanged()
+    {
+        OnPropertyChanged(nameof(ErrorNotice));
+        OnPropertyChanged(nameof(PendingErrorNotices));
+    }
+
+    /// <summary>Shown when a failure's details are only in the log.</summary>
+    private void ShowErrorWithLog(string message)
+        => ShowError(message, LogFilePath is null ? null : "Save log…",
+            LogFilePath is null ? null : () => FireAndForget(ExportDiagnosticLog(), nameof(ExportDiagnosticLog)));
+}
@@ -0,0 +1,105 @@
+using RailReader.Core;
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Quick jump (G): numbered badges on the current page's navigable blocks; typing a badge's label
+// frames that block at the current zoom. Escape (or any other key) cancels.
+public sealed partial class MainWindowViewModel
+{
+    private QuickJumpInput? _quickJump;
+    private IReadOnlyList<QuickJumpHint> _quickJumpHints = [];
+    private Viewport? _quickJumpViewport;
+    private int _quickJumpPage;
+
+    public bool QuickJumpActive => _quickJump is not null;
+
+    /// <summary>Label the focused view's navigable blocks and wait for a label to be typed.</summary>
+    public void BeginQuickJump()
+    {
+        if (IsScanAllActive) return;
+        var vp = _controller.FocusedViewport;
+        if (vp?.Owner is not { } doc || !vp.Rail.Active)
+        {
+            ShowStatusToast("Quick jump is available while rail-reading (zoom in)");
+            return;
+        }
+        if (!doc.TryGetAnalysis(vp.CurrentPage, vp.AnalysisParams, out var analysis))
+        {
+            ShowStatusToast("This page hasn't been analysed yet");
+            return;
+        }
+
+        var navigable = new List<int>();
+        for (int i = 0; i < analysis.Blocks.Count; i++)
+            if (_controller.Config.NavigableRoles.Contains(analysis.Blocks[i].Role))
+                navigable.Add(i);
+        if (navigable.Count == 0)
+        {
+            ShowStatusToast("No blocks to jump to on this page");
+            return;
+        }
+
+        var labels = QuickJumpInput.Labels(navigable.Count);
+        var hints = new QuickJumpHint[labels.Count];
+        for (int i = 0; i < labels.Count; i++)
+        {
+            var box = analysis.Blocks[navigable[i]].BBox;
+            hints[i] = new QuickJumpHint(labels[i], navigable[i], (float)box.X, (float)box.Y);
+        }
+
+        _quickJump = new QuickJumpInput(labels);
+        _quickJumpHints = hints;
+        _quickJumpViewport = vp;
+        _quickJumpPage = vp.CurrentPage;
+        OnPropertyChanged(nameof(QuickJumpActive));
+        InvalidateOverlay();
+        ShowStatusToast("Jump to block: type its label (Esc cancels)");
+    }
+
+    /// <summary>Feed a typed label character. Selecting a block frames it and ends quick jump; a key
+    /// that matches no label ends it with a toast.</summary>
+    public void FeedQuickJump(char key)
+    {
+        if (_quickJump is not { } input) return;
+        switch (input.Feed(key, out int index))
+        {
+            case QuickJumpInput.Outcome.Pending:
+                InvalidateOverlay(); // narrow the badges to the typed prefix
+                break;
+            case QuickJumpInput.Outcome.Selected:
+                var hint = _quickJumpHints[index];
+                var vp = _quickJumpViewport;
+                CancelQuickJump();
+                // Only frame if the view is still where the badges were drawn.
+                if (vp is not null && ReferenceEquals(vp, _controller.FocusedViewport) && vp.CurrentPage == _quickJumpPage)
+                    SmoothlyFrameBlock(hint.BlockIndex, vp.Camera.Zoom);
+                break;
+            default:
+                CancelQuickJump();
+                ShowStatusToast($"No block labelled \"{key}\"");
+                break;
+        }
+    }
+
+    public void CancelQuickJump()
+    {
+        if (_quickJump is null) return;
+        _quickJump = null;
+        _quickJumpHints = [];
+        _quickJumpViewport = null;
+        OnPropertyChanged(nameof(QuickJumpActive));
+        InvalidateOverlay();
+    }
+
+    /// <summary>Badges to draw on <paramref name="vp"/>: the pending quick jump's, narrowed to what's
+    /// been typed, or null when quick jump isn't running on that view and page.</summary>
+    internal IReadOnlyList<QuickJumpHint>? QuickJumpHintsFor(Viewport? vp)
+    {
+        if (_quickJump is not { } input || vp is null
+            || !ReferenceEquals(vp, _quickJumpViewport) || vp.CurrentPage != _quickJumpPage)
+            return null;
+        if (input.Typed.Length == 0) return _quickJumpHints;
+        return _quickJumpHints.Where(h => h.Label.StartsWith(input.Typed, StringComparison.Ordinal)).ToArray();
+    }
+}
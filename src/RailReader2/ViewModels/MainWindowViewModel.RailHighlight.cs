@@ -0,0 +1,44 @@
+using RailReader.Core.Models;
+
+namespace RailReader2.ViewModels;
+
+// Rail highlights: M marks the current rail block, Shift+M the current line, as an ordinary highlight
+// annotation — so it is saved with the document's other annotations, listed (and deletable) in the
+// Comments pane, undoable, and included in Markdown export. Each new mark takes the next palette colour.
+public sealed partial class MainWindowViewModel
+{
+    private static readonly string[] RailHighlightPalette = ["#FFE14D", "#7CD67C", "#6FB7FF", "#FF8AD0"];
+    private int _railHighlightColour;
+
+    public void HighlightCurrentBlock() => AddRailHighlight(lineOnly: false);
+
+    public void HighlightCurrentLine() => AddRailHighlight(lineOnly: true);
+
+    private void AddRailHighlight(bool lineOnly)
+    {
+        if (CurrentRailBlockOrToast() is not ({ } doc, { } vp, { } block)) return;
+
+        List<HighlightRect> rects;
+        if (block.Lines.Count == 0)
+            rects = [new HighlightRect(block.BBox.X, block.BBox.Y, block.BBox.W, block.BBox.H)];
+        else if (lineOnly)
+            rects = [LineRect(block.Lines[Math.Clamp(vp.Rail.CurrentLine, 0, block.Lines.Count - 1)])];
+        else
+            rects = block.Lines.Select(LineRect).ToList();
+
+        var colour = RailHighlightPalette[_railHighlightColour];
+        _railHighlightColour = (_railHighlightColour + 1) % RailHighlightPalette.Length;
+        var highlight = new HighlightAnnotation { Rects = rects, Color = colour, Opacity = 0.4f };
+
+        var action = new AddAnnotationAction(vp.CurrentPage, highlight);
+        action.Redo(doc.Annotations);
+        doc.PushUndoAction(action);
+        doc.MarkAnnotationsDirty();
+        InvalidateAnnotations();
+        NotifyAnnotationsMutated();
+        ShowStatusToast(lineOnly && block.Lines.Count > 0 ? "Highlighted line" : "Highlighted block");
+
+        // LineInfo.Y is the line's centre; highlight rects are top-left based.
+        static HighlightRect LineRect(LineInfo l) => new(l.X, l.Y - l.Height / 2f, l.Width, l.Height);
+    }
+}
@@ -0,0 +1,88 @@
+using Avalonia.Controls;
+using Avalonia.Platform.Storage;
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Reading stats: per-tab time per page/block while rail-reading (Help → Reading Stats), clocked on
+// reading-context changes and paused while the window is in the background.
+public sealed partial class MainWindowViewModel
+{
+    private const int MinReadingStatsIdleMinutes = 1, MaxReadingStatsIdleMinutes = 30;
+
+    public int ReadingStatsIdleMinutes
+    {
+        get => _shellPrefs.ReadingStatsIdleMinutes;
+        set
+        {
+            value = Math.Clamp(value, MinReadingStatsIdleMinutes, MaxReadingStatsIdleMinutes);
+            if (_shellPrefs.ReadingStatsIdleMinutes == value) return;
+            _shellPrefs.ReadingStatsIdleMinutes = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(ReadingStatsIdleMinutes));
+        }
+    }
+
+    /// <summary>Clock the active tab onto its focused view's current rail position; every other tab
+    /// (and the active one when it isn't rail-reading) stops counting.</summary>
+    private void RecordReadingStats()
+    {
+        var vp = _controller.FocusedViewport;
+        var idle = TimeSpan.FromMinutes(ReadingStatsIdleMinutes);
+        foreach (var tab in Tabs)
+        {
+            if (ReferenceEquals(tab, ActiveTab) && vp is { Rail.Active: true }
+                && ReferenceEquals(vp.Owner, tab.State))
+            {
+                tab.Stats.IdleTimeout = idle;
+                tab.Stats.Mark(vp.CurrentPage, vp.Rail.CurrentBlock, vp.Rail.CurrentLine);
+            }
+            else
+            {
+                tab.Stats.Pause();
+            }
+        }
+    }
+
+    public void PauseReadingStats()
+    {
+        foreach (var tab in Tabs) tab.Stats.Pause();
+    }
+
+    public void ResumeReadingStats() => RecordReadingStats();
+
+    /// <summary>Save <paramref name="tab"/>'s per-block breakdown as <c>page,block,seconds</c> CSV,
+    /// picking the file from <paramref name="owner"/> (the stats window).</summary>
+    public async Task ExportReadingStats(TabViewModel tab, TopLevel owner)
+    {
+        tab.Stats.Flush();
+        if (tab.Stats.Entries().Count == 0)
+        {
+            ShowStatusToast("No reading time recorded yet");
+            return;
+        }
+
+        var file = await owner.StorageProvider.SaveFilePickerAsync(new FilePickerSaveOptions
+        {
+            Title = "Export Reading Stats",
+            DefaultExtension = "csv",
+            FileTypeChoices = [new FilePickerFileType("CSV Files") { Patterns = ["*.csv"] }],
+            SuggestedFileName = Path.GetFileNameWithoutExtension(tab.FilePath) + "_reading_stats.csv",
+        });
+        if (file is null) return;
+
+        var outputPath = file.TryGetLocalPath() ?? file.Path.LocalPath;
+        if (outputPath is null) return;
+
+        try
+        {
+            File.WriteAllText(outputPath, tab.Stats.ToCsv());
+            ShowStatusToast($"Reading stats exported to {Path.GetFileName(outputPath)}");
+        }
+        catch (Exception ex)
+        {
+            _logger.Error("[ReadingStats] Export failed", ex);
+            ShowErrorWithLog("Failed to export reading stats");
+        }
+    }
+}
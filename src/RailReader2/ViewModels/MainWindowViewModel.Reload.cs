@@ -0,0 +1,258 @@
+using System.Collections.Specialized;
+using Avalonia.Threading;
+using CommunityToolkit.Mvvm.Input;
+using RailReader.Core;
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Reload from disk: File → Reload (F5) on demand, and automatically when an open PDF changes on disk
+// (e.g. regenerated by LaTeX). Settings → Appearance → General holds the auto-reload switch.
+public sealed partial class MainWindowViewModel
+{
+    private DocumentFileWatcher? _fileWatcher;
+    private bool _reloading;
+    // Files that changed again while a reload was in flight (a second LaTeX pass); reloaded once more
+    // when it finishes, so the tabs don't stay on the copy from the first write.
+    private readonly HashSet<string> _reloadAgain = new(StringComparer.Ordinal);
+
+    /// <summary>A document was reloaded from disk: the view moves split panes and tear-offs showing the
+    /// old model (first argument) onto the new one, each re-bound to the tab that replaced its own
+    /// (old tab → new tab). Raised before the old model is disposed.</summary>
+    public event Action<DocumentModel, IReadOnlyDictionary<TabViewModel, TabViewModel>>? DocumentReloaded;
+
+    /// <summary>Reload open documents when their file changes on disk. Persisted app-wide (shell
+    /// sidecar); takes effect immediately.</summary>
+    public bool AutoReloadEnabled
+    {
+        get => _shellPrefs.AutoReload;
+        set
+        {
+            if (_shellPrefs.AutoReload == value) return;
+            _shellPrefs.AutoReload = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(AutoReloadEnabled));
+            ApplyFileWatcher();
+        }
+    }
+
+    /// <summary>Start or stop watching to match <see cref="AutoReloadEnabled"/>; while on, the watched
+    /// set follows the open tabs. Idempotent.</summary>
+    private void ApplyFileWatcher()
+    {
+        if (!AutoReloadEnabled)
+        {
+            if (_fileWatcher is null) return;
+            Tabs.CollectionChanged -= OnTabsChangedForFileWatch;
+            _fileWatcher.Dispose();
+            _fileWatcher = null;
+            return;
+        }
+        if (_fileWatcher is null)
+        {
+            // Change reports arrive on a timer thread; reloading touches UI-thread state.
+            _fileWatcher = new DocumentFileWatcher(
+                path => Dispatcher.UIThread.Post(() => OnWatchedFileChanged(path)), _logger);
+            Tabs.CollectionChanged += OnTabsChangedForFileWatch;
+        }
+        _fileWatcher.Sync(Tabs.Select(t => t.FilePath));
+    }
+
+    private void OnTabsChangedForFileWatch(object? sender, NotifyCollectionChangedEventArgs e)
+        => _fileWatcher?.Sync(Tabs.Select(t => t.FilePath));
+
+    private void OnWatchedFileChanged(string path)
+    {
+        if (!AutoReloadEnabled) return;
+        var full = Path.GetFullPath(path);
+        var tab = Tabs.FirstOrDefault(t => string.Equals(Path.GetFullPath(t.FilePath), full, StringComparison.Ordinal));
+        if (tab is null) return;
+        if (_reloading)
+        {
+            _reloadAgain.Add(full);
+            return;
+        }
+        // Scan All owns the document until it finishes; an encrypted PDF is also where Core saves
+        // annotations in place, so its own saves would otherwise bounce back as reloads.
+        if (IsScanAllActive || !string.IsNullOrEmpty(tab.Pdf.Password))
+        {
+            ShowStatusToast($"{Path.GetFileName(path)} changed on disk — press F5 to reload");
+            return;
+        }
+        FireAndForget(ReloadDocument(tab), nameof(ReloadDocument));
+    }
+
+    [RelayCommand]
+    public async Task ReloadActiveDocument()
+    {
+        if (ActiveTab is { } tab) await ReloadDocument(tab);
+    }
+
+    /// <summary>Re-open <paramref name="tab"/>'s file from disk. Every tab on the file shares one
+    /// document model, so they all reload together. The new copy is opened (with the current password
+    /// tried first) and its first page rendered before anything is closed; if that fails — e.g. a PDF
+    /// a LaTeX run is still writing — the open tabs are left as they are and the file stays watched, so
+    /// the next good build reloads. On success each tab is replaced in place, back on its page (clamped
+    /// if the document got shorter), camera and rail line, or its sticky fit; split panes and tear-offs
+    /// on the file move over too. Layout analysis and page caches start fresh.</summary>
+    public async Task ReloadDocument(TabViewModel tab)
+    {
+        if (IsScanAllActive || _reloading) return;
+        var path = tab.FilePath;
+        var name = Path.GetFileName(path);
+        if (!File.Exists(path))
+        {
+            ShowStatusToast($"Can't reload: {name} no longer exists");
+            return;
+        }
+        string? password = string.IsNullOrEmpty(tab.Pdf.Password) ? null : tab.Pdf.Password;
+
+        _reloading = true;
+        try
+        {
+            DocumentModel? state;
+            try
+            {
+                state = await LoadDocumentModel(path, password);
+            }
+            catch (Exception ex)
+            {
+                _logger.Info($"[Reload] Keeping the open copy of {path}: {ex.Message}");
+                ShowStatusToast($"Couldn't reload {name} — keeping the open copy");
+                return;
+            }
+            // Password prompt cancelled, or a tab on the file was closed while loading.
+            if (state is null || !Tabs.Any(t => ReferenceEquals(t.State, tab.State)))
+            {
+                state?.Dispose();
+                return;
+            }
+            SwapReloadedDocument(tab.State, state);
+        }
+        finally
+        {
+            _reloading = false;
+            foreach (var again in _reloadAgain)
+                Dispatcher.UIThread.Post(() => OnWatchedFileChanged(again));
+            _reloadAgain.Clear();
+        }
+        ShowStatusToast($"Reloaded {name}");
+    }
+
+    /// <summary>A tab's view before a reload: its place in the tab strip, page, camera and size, and
+    /// the page-block index and line the rail was on (-1 when not rail-reading).</summary>
+    private sealed record ViewBeforeReload(TabViewModel Tab, int Index, int Page, double Zoom,
+        double OffsetX, double OffsetY, double Width, double Height, int RailBlock, int RailLine);
+
+    private static ViewBeforeReload CaptureView(TabViewModel tab, int index)
+    {
+        var vp = tab.Viewport;
+        int block = -1;
+        if (vp.Rail.Active && vp.Rail.CurrentNavigableBlock is { } current
+            && tab.State.TryGetAnalysis(vp.CurrentPage, vp.AnalysisParams, out var analysis))
+        {
+            for (int i = 0; i < analysis.Blocks.Count; i++)
+                if (ReferenceEquals(analysis.Blocks[i], current)) { block = i; break; }
+        }
+        return new(tab, index, vp.CurrentPage, vp.Camera.Zoom, vp.Camera.OffsetX, vp.Camera.OffsetY,
+            vp.Width, vp.Height, block, vp.Rail.CurrentLine);
+    }
+
+    /// <summary>Replace every tab on <paramref name="old"/> with one on <paramref name="fresh"/> at the
+    /// same tab index and view, move the surfaces showing <paramref name="old"/> over, then dispose it.
+    /// The old model stays alive until nothing draws from it, so a split pane or tear-off survives and
+    /// a single-tab document never passes through the empty state.</summary>
+    private void SwapReloadedDocument(DocumentModel old, DocumentModel fresh)
+    {
+        _controller.SaveAllReadingPositions();
+        var views = Tabs
+            .Select((t, i) => (Tab: t, Index: i))
+            .Where(x => ReferenceEquals(x.Tab.State, old))
+            .Select(x => CaptureView(x.Tab, x.Index))
+            .ToList();
+        var focused = _controller.FocusedViewport;
+
+        // The first tab takes the new model's primary view; the rest share it like duplicate tabs.
+        _controller.AddDocument(fresh);
+        var replacements = new Dictionary<TabViewModel, TabViewModel>();
+        foreach (var view in views)
+        {
+            int page = Math.Clamp(view.Page, 0, Math.Max(0, fresh.PageCount - 1));
+            var tab = replacements.Count == 0 ? CreateDocumentTab(fresh) : CreateSharedViewportTab(fresh, page, cloneFrom: null);
+            tab.CarryOverFrom(view.Tab);
+            RestoreView(tab, view, page);
+            replacements[view.Tab] = tab;
+            Tabs[view.Index] = tab;
+        }
+
+        DocumentReloaded?.Invoke(old, replacements);
+        if (views.FirstOrDefault(v => ReferenceEquals(v.Tab.Viewport, focused)) is { } focusedView)
+            FocusViewport(replacements[focusedView.Tab].Viewport);
+        OnPropertyChanged(nameof(ActiveTab));
+
+        // Nothing shows the old model any more — retire it as CloseTab does for a last tab.
+        if (_portalViewport?.Owner is { } pOwner && ReferenceEquals(pOwner, old))
+            RequestPortalViewTeardown();
+        foreach (var view in views)
+        {
+            view.Tab.Dispose();
+            Services.PortalSetManager.Default.Release(view.Tab.FilePath);
+        }
+        int docIdx = _controller.Documents.IndexOf(old);
+        if (docIdx >= 0) _controller.CloseDocument(docIdx);
+        foreach (var view in views)
+            DisposeFreezeFor(view.Tab.Viewport);
+
+        if (ActiveTab is { } active && replacements.ContainsValue(active))
+        {
+            ReapplyFitMode(active.Viewport);
+            SeatReloadedRail();
+        }
+        EvaluatePortals();
+        InvalidateAll();
+        RequestAnimationFrame();
+        StartBackgroundAnalysis();
+    }
+
+    /// <summary>Put <paramref name="view"/>'s page, size and camera on <paramref name="tab"/>'s new
+    /// viewport and submit the page for analysis. The rail block/line is re-seated later by
+    /// <see cref="SeatReloadedRail"/>, once that analysis is in; a sticky fit is re-applied instead of
+    /// the old zoom by the caller.</summary>
+    private void RestoreView(TabViewModel tab, ViewBeforeReload view, int page)
+    {
+        var vp = tab.Viewport;
+        if (view.Width > 0 && view.Height > 0) vp.SetSize(view.Width, view.Height);
+        if (vp.CurrentPage != page)
+        {
+            vp.CurrentPage = page;
+            vp.LoadPageBitmap();
+        }
+        vp.Camera.Zoom = view.Zoom;
+        vp.Camera.OffsetX = view.OffsetX;
+        vp.Camera.OffsetY = view.OffsetY;
+        vp.ClampCamera(vp.Width, vp.Height);
+        vp.UpdateRailZoom(vp.Width, vp.Height);
+        tab.FitModePage = page;
+        tab.ReloadRailSeat = view.RailBlock >= 0 && page == view.Page ? (page, view.RailBlock, view.RailLine) : null;
+        tab.State.SubmitAnalysis(vp, _controller.Worker, _controller.Config.NavigableRoles);
+    }
+
+    /// <summary>Frame the active tab's rail back on the block and line it was reading before a reload,
+    /// once the new copy's analysis of that page is in. Dropped if the reader has moved to another
+    /// page meanwhile, or the page now has fewer blocks.</summary>
+    private void SeatReloadedRail()
+    {
+        if (ActiveTab is not { ReloadRailSeat: { } seat } tab) return;
+        var vp = tab.Viewport;
+        if (vp.CurrentPage != seat.Page)
+        {
+            tab.ReloadRailSeat = null;
+            return;
+        }
+        if (_controller.FocusedViewport != vp || !tab.State.TryGetAnalysis(seat.Page, vp.AnalysisParams, out var analysis))
+            return;
+        tab.ReloadRailSeat = null;
+        if (seat.Block < analysis.Blocks.Count)
+            SmoothlyFrameBlock(seat.Block, vp.Camera.Zoom, line: seat.Line);
+    }
+}
@@ -0,0 +1,72 @@
+using RailReader2.Services;
+
+namespace RailReader2.ViewModels;
+
+// Read aloud: speak the current rail line through the platform's speech tool (T). Settings →
+// Rail Reading → Read Aloud holds the on/off switch and the rate (shell sidecar prefs).
+public sealed partial class MainWindowViewModel
+{
+    private SpeechService? _speech;
+
+    public bool SpeechEnabled
+    {
+        get => _shellPrefs.SpeechEnabled;
+        set
+        {
+            if (_shellPrefs.SpeechEnabled == value) return;
+            _shellPrefs.SpeechEnabled = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(SpeechEnabled));
+            if (!value) _speech?.Stop();
+        }
+    }
+
+    public int SpeechWordsPerMinute
+    {
+        get => _shellPrefs.SpeechWordsPerMinute;
+        set
+        {
+            value = Math.Clamp(value, SpeechService.MinWordsPerMinute, SpeechService.MaxWordsPerMinute);
+            if (_shellPrefs.SpeechWordsPerMinute == value) return;
+            _shellPrefs.SpeechWordsPerMinute = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(SpeechWordsPerMinute));
+        }
+    }
+
+    /// <summary>Speak the focused view's current rail line, cutting off any line still being spoken.
+    /// Every way this can't happen (switched off, not rail-reading, no text under the line, no speech
+    /// tool installed) ends in a status toast rather than silence.</summary>
+    public void SpeakCurrentLine()
+    {
+        if (!SpeechEnabled)
+        {
+            ShowStatusToast("Read aloud is off (Settings → Rail Reading)");
+            return;
+        }
+        if (GetReadingPosition() is not { } pos)
+        {
+            ShowStatusToast("Read aloud speaks the current rail line (zoom in to rail-read)");
+            return;
+        }
+        if (string.IsNullOrWhiteSpace(pos.LineText))
+        {
+            ShowStatusToast("No text on this line");
+            return;
+        }
+
+        _speech ??= new SpeechService(_logger);
+        if (!_speech.IsAvailable)
+            ShowStatusToast("No speech engine found — install espeak-ng or speech-dispatcher");
+        else
+            FireAndForget(SpeakLineAsync(_speech, pos.LineText), "Read line aloud");
+    }
+
+    private async Task SpeakLineAsync(SpeechService speech, string text)
+    {
+        if (!await speech.SpeakAsync(text, SpeechWordsPerMinute))
+            ShowStatusToast("Couldn't start speech (see log)");
+    }
+
+    public void StopSpeaking() => _speech?.Stop();
+}
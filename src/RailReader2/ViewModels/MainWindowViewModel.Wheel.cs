@@ -0,0 +1,93 @@
+namespace RailReader2.ViewModels;
+
+// Mouse wheel: by default the wheel zooms at the cursor and Ctrl+wheel scrolls along the rail line
+// (Core's HandleZoom ctrl path). With "wheel scrolls" on it behaves like most document viewers instead:
+// the wheel scrolls (pans, or one rail line per notch), Shift+wheel scrolls sideways, and Ctrl+wheel
+// zooms. Invert flips scrolling only (never zoom); sensitivity scales both. All shell preferences.
+public sealed partial class MainWindowViewModel
+{
+    // Wheel delta → Core's zoom delta (one notch ≈ 9% zoom via HandleZoom's 0.003 scale).
+    private const double WheelZoomPerNotch = 30.0;
+    // Wheel delta → screen pixels when the wheel pans.
+    private const double WheelPanPerNotch = 60.0;
+    internal const double MinWheelSensitivity = 0.25;
+    internal const double MaxWheelSensitivity = 4.0;
+
+    // Partial notches toward the next rail line (touchpads deliver fractions of a notch).
+    private double _wheelLineAccum;
+
+    public bool WheelScrolls
+    {
+        get => _shellPrefs.WheelScrolls;
+        set
+        {
+            if (_shellPrefs.WheelScrolls == value) return;
+            _shellPrefs.WheelScrolls = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(WheelScrolls));
+        }
+    }
+
+    public bool InvertScroll
+    {
+        get => _shellPrefs.InvertScroll;
+        set
+        {
+            if (_shellPrefs.InvertScroll == value) return;
+            _shellPrefs.InvertScroll = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(InvertScroll));
+        }
+    }
+
+    public double WheelSensitivity
+    {
+        get => Math.Clamp(_shellPrefs.WheelSensitivity, MinWheelSensitivity, MaxWheelSensitivity);
+        set
+        {
+            value = Math.Clamp(value, MinWheelSensitivity, MaxWheelSensitivity);
+            if (_shellPrefs.WheelSensitivity == value) return;
+            _shellPrefs.WheelSensitivity = value;
+            _shellPrefs.Save();
+            OnPropertyChanged(nameof(WheelSensitivity));
+        }
+    }
+
+    /// <summary>A wheel event over the view: <paramref name="notches"/> is the vertical delta (positive
+    /// = away from the user), the cursor is in view coordinates.</summary>
+    public void HandleWheel(double notches, double cursorX, double cursorY, bool ctrl, bool shift)
+    {
+        double scaled = notches * WheelSensitivity;
+        double scroll = InvertScroll ? -scaled : scaled;
+        if (!WheelScrolls)
+        {
+            // Zoom, or (Ctrl) Core's scroll along the rail line — which is a scroll, so it honours invert.
+            HandleZoom((ctrl ? scroll : scaled) * WheelZoomPerNotch, cursorX, cursorY, ctrl);
+            return;
+        }
+        if (ctrl)
+        {
+            HandleZoom(scaled * WheelZoomPerNotch, cursorX, cursorY, ctrlHeld: false);
+            return;
+        }
+
+        bool rail = _controller.FocusedViewport?.Rail.Active == true;
+        if (shift)
+        {
+            if (rail) HandleZoom(scroll * WheelZoomPerNotch, cursorX, cursorY, ctrlHeld: true);
+            else HandlePan(scroll * WheelPanPerNotch, 0);
+        }
+        else if (rail) StepRailLines(scroll);
+        else HandlePan(0, scroll * WheelPanPerNotch);
+    }
+
+    /// <summary>One rail line per whole notch, carrying fractions over; a change of direction drops
+    /// the carried part so reversing responds at once.</summary>
+    private void StepRailLines(double scroll)
+    {
+        if (Math.Sign(scroll) != Math.Sign(_wheelLineAccum)) _wheelLineAccum = 0;
+        _wheelLineAccum += scroll;
+        for (; _wheelLineAccum <= -1; _wheelLineAccum += 1) HandleArrowDown();
+        for (; _wheelLineAccum >= 1; _wheelLineAccum -= 1) HandleArrowUp();
+    }
+}
@@ -1,6 +1,7 @@
 using System.Collections.ObjectModel;
 using System.Diagnostics;
 using Avalonia.Controls;
+using Avalonia.Platform.Storage;
 using Avalonia.Threading;
 using CommunityToolkit.Mvvm.ComponentModel;
 using CommunityToolkit.Mvvm.Input;
@@ -16,7 +17,10 @@ namespace RailReader2.ViewModels;
 /// <summary>The side-panel tabs, used by ShowPane for menu-driven pane navigation. The enum order
 /// matches the accordion's grid-row order (see <c>OutlinePanel</c>), so a section's row is just
 /// <c>(int)Pane</c> — keep new panes appended.</summary>
-public enum SidePane { Outline, Bookmarks, Index, Search, Comments, Portals }
+public enum SidePane { Outline, Bookmarks, Index, Search, Comments, Portals, Pages }
+
+/// <summary>UI theme choice; the order matches Settings' theme selector.</summary>
+public enum AppTheme { Light, Dark, System }
 
 // Core infrastructure: fields, constructor, animation, invalidation, config, status toast.
 // See partial class files for: Documents, Navigation, Annotations, Search.
@@ -151,8 +155,8 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
     [ObservableProperty] private bool _showSettings;
     [ObservableProperty] private bool _showAbout;
     [ObservableProperty] private bool _showShortcuts;
+    [ObservableProperty] private bool _showReadingStats;
     [ObservableProperty] private bool _showGoToPage;
-    [ObservableProperty] private string? _cleanupMessage;
 
     [ObservableProperty] private bool _isFullScreen;
     [ObservableProperty] private bool _showFullScreenHeader;
@@ -198,7 +202,7 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
     // from a wedged analysis worker without locking the UI indefinitely.
     private const int ScanAllStallTickLimit = 600;
 
-    /// <summary>True when the tab bar should be visible (not fullscreen, or hovering at top edge).</summary>
+    /// <summary>True when the menu and tab bars should be visible (not fullscreen, or hovering at top edge).</summary>
     public bool IsTabBarVisible => !IsFullScreen || ShowFullScreenHeader;
 
     /// <summary>True when the status bar should be visible (not fullscreen, or hovering at bottom edge).</summary>
@@ -341,9 +345,15 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
                 _logger.Debug($"[ONNX] Starting worker with model: {resolution.ModelPath}");
                 _controller.InitializeWorker(resolution.Capabilities, resolution.Factory);
                 ActiveLayoutModelName = resolution.DisplayName;
+                _layoutClassNames = resolution.Capabilities.Classes.Select(c => c.Name).ToList();
             }
         }
-        catch (Exception ex) { _logger.Error("[ONNX] Worker init failed", ex); }
+        catch (Exception ex)
+        {
+            _logger.Error("[ONNX] Worker init failed", ex);
+            ShowError("The layout model failed to load — rail mode uses basic navigation", "Settings",
+                () => ShowSettings = true);
+        }
         _controller.StateChanged += OnControllerStateChanged;
         _controller.StatusMessage += ShowStatusToast;
         // Push-based reading-context updates: Core fires these (on the UI thread) the moment the page
@@ -353,6 +363,8 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
         // old facade only ever fired for the focused view, so this is equivalent.
         WireAnnotationStoreSignals();
         SetupPollTimer();
+        ApplyFollowAlongServer();
+        ApplyFileWatcher();
     }
 
     // Last-published menu-gating values, so a spurious ActiveTab raise re-publishes nothing.
@@ -573,13 +585,17 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
 
     private void AnnounceAccessibilityState() => _invalidation?.AnnounceAccessibility?.Invoke();
 
-    /// <summary>The reading context (current page / rail line) changed: re-announce accessibility state
-    /// and re-evaluate which portal the reading position is inside. Driven by Core's PageChanged /
+    /// <summary>The reading context (current page / rail line) changed: re-announce accessibility state,
+    /// re-evaluate which portal the reading position is inside, tell a follow-along client, sample
+    /// the reading pace, and clock reading time for the stats. Driven by Core's PageChanged /
     /// ReadingPositionChanged callbacks — the single place those two converge.</summary>
     private void OnReadingContextChanged()
     {
         AnnounceAccessibilityState();
         EvaluatePortals();
+        PublishFollowAlongPosition();
+        RecordReadingProgress();
+        RecordReadingStats();
         // A page change can add/remove tables (CanFreeze) or take the focused view off its frozen page
         // (IsFrozen auto-clears in GetFreezeTiles) — keep the Freeze toggle's label/enable in sync.
         OnPropertyChanged(nameof(IsFrozen));
@@ -600,7 +616,10 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
             if (tab is not null && !_animationRequested)
                 tab.SubmitPendingLookahead(_controller.Worker);
             if (gotResults)
+            {
                 InvalidateOverlay();
+                SeatReloadedRail();
+            }
             // Only force a portal re-evaluation when something is still waiting on analysis (a pinned
             // target's page, or an automatic reference's caption page) — otherwise the
             // reading-position callbacks + memo already cover the steady case, and forcing on every
@@ -608,6 +627,7 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
             EvaluatePortals(forceRender: gotResults && PortalResolvePending);
             if (needsAnim)
                 RequestAnimationFrame();
+            UpdateAnalysisActivity();
             bool workerBusy = _controller.Worker is not null && !_controller.Worker.IsIdle;
             if (!workerBusy) _pollTimer?.Stop();
         };
@@ -624,7 +644,10 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
             // Poll results even if no animation frame is running
             var (gotResults, _, _) = _controller.PollAnalysisResults();
             if (gotResults)
+            {
                 InvalidateOverlay();
+                SeatReloadedRail();
+            }
             // As above: force only when a pinned target or auto reference is still resolving, so
             // background read-ahead (one result per analysed page) doesn't bypass the memo on every page.
             EvaluatePortals(forceRender: gotResults && PortalResolvePending);
@@ -633,12 +656,31 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
             bool railActive = _controller.FocusedViewport?.Owner?.Rail.Active == true;
             if (!railActive && _controller.Worker.IsIdle && hasWork)
                 _controller.TrySubmitBackgroundReadAhead();
+            UpdateAnalysisActivity();
 
-            if (!hasWork)
+            // Keep ticking until the last submitted page lands, so the activity readout clears.
+            if (!hasWork && _controller.Worker.IsIdle)
                 _backgroundTimer?.Stop();
         };
     }
 
+    /// <summary>Status-bar readout while the layout worker is busy: how much of the active document is
+    /// analyzed ("37/120"), or just that it's working when the page in flight belongs to another tab's
+    /// document. Null when idle. Core's worker reports only idle/busy, not which page it's on.</summary>
+    [ObservableProperty] private string? _analysisActivity;
+
+    private void UpdateAnalysisActivity()
+    {
+        if (_controller.Worker is not { IsIdle: false })
+        {
+            AnalysisActivity = null;
+            return;
+        }
+        AnalysisActivity = ActiveTab?.State is { } doc && doc.AnalysedPageCount < doc.PageCount
+            ? $"{doc.AnalysedPageCount}/{doc.PageCount}"
+            : "";
+    }
+
     /// <summary>
     /// Start the background analysis timer if there's work to do.
     /// Called after adding a document.
@@ -784,7 +826,11 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
 
     public void Dispose()
     {
+        FlushConfigSave();
         UnwireFocusedSignals();
+        _followAlong?.Dispose();
+        _speech?.Dispose();
+        _fileWatcher?.Dispose();
         DisposePortalImages();
         DisposeFreezeImages();
         _controller.Dispose();
@@ -853,26 +899,73 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
     public void RunCleanup()
     {
         var (removed, freed) = CleanupService.RunCleanup();
-        CleanupMessage = CleanupService.FormatReport(removed, freed);
+        ShowStatusToast(CleanupService.FormatReport(removed, freed));
     }
 
-    public void SetDarkMode(bool dark)
+    /// <summary>Light, dark, or following the OS. Light/Dark are Core's <c>dark_mode</c>; System is a
+    /// shell preference layered on top (Core's flag is left as it was, so turning System off restores
+    /// the previous explicit choice).</summary>
+    public AppTheme Theme
     {
-        AppConfig.DarkMode = dark;
-        AppConfig.Save();
-        Avalonia.Application.Current!.RequestedThemeVariant =
-            dark ? Avalonia.Styling.ThemeVariant.Dark : Avalonia.Styling.ThemeVariant.Light;
+        get => _shellPrefs.FollowSystemTheme ? AppTheme.System : AppConfig.DarkMode ? AppTheme.Dark : AppTheme.Light;
+        set
+        {
+            if (value != AppTheme.System && AppConfig.DarkMode != (value == AppTheme.Dark))
+            {
+                AppConfig.DarkMode = value == AppTheme.Dark;
+                AppConfig.SaveWithBackup();
+            }
+            if (_shellPrefs.FollowSystemTheme != (value == AppTheme.System))
+            {
+                _shellPrefs.FollowSystemTheme = value == AppTheme.System;
+                _shellPrefs.Save();
+            }
+            ApplyTheme();
+            OnPropertyChanged(nameof(Theme));
+        }
     }
 
+    /// <summary>Set Avalonia's requested theme from <see cref="Theme"/>; Default follows the OS.</summary>
+    public void ApplyTheme()
+        => Avalonia.Application.Current!.RequestedThemeVariant = Theme switch
+        {
+            AppTheme.System => Avalonia.Styling.ThemeVariant.Default,
+            AppTheme.Dark => Avalonia.Styling.ThemeVariant.Dark,
+            _ => Avalonia.Styling.ThemeVariant.Light,
+        };
+
     public void OnConfigChanged()
     {
         _controller.OnConfigChanged(_appConfig.ToCoreSettings());
-        _appConfig.Save();
+        ScheduleConfigSave();
         ApplyFontScale();
         InvalidateAll();
         OnPropertyChanged(nameof(ActiveTab));
     }
 
+    // Settings sliders call OnConfigChanged on every tick while dragged; the change applies at once,
+    // but config.json is written at most this often (and once more on exit).
+    private const int ConfigSaveDebounceMs = 500;
+    private DispatcherTimer? _configSaveTimer;
+
+    private void ScheduleConfigSave()
+    {
+        if (_configSaveTimer is null)
+        {
+            _configSaveTimer = new DispatcherTimer { Interval = TimeSpan.FromMilliseconds(ConfigSaveDebounceMs) };
+            _configSaveTimer.Tick += (_, _) => FlushConfigSave();
+        }
+        // Left running rather than restarted, so a long drag still saves every interval.
+        if (!_configSaveTimer.IsEnabled) _configSaveTimer.Start();
+    }
+
+    private void FlushConfigSave()
+    {
+        if (_configSaveTimer is not { IsEnabled: true }) return;
+        _configSaveTimer.Stop();
+        _appConfig.SaveWithBackup();
+    }
+
     public void OnSliderChanged() => _controller.OnSliderChanged(_appConfig.ToCoreSettings());
 
     // --- Scan All ---
@@ -894,7 +987,11 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
         }
 
         IsScanAllActive = true;
-        if (ActiveTab is { } tab) tab.FullScanPeekIndex = null;
+        if (ActiveTab is { } tab)
+        {
+            tab.FullScanPeekIndex = null;
+            tab.FullScanAnalyses = null;
+        }
         _scanAllOriginalWindowPages = _appConfig.BackgroundAnalysisWindowPages;
         _scanAllLastScanned = doc.AnalysedPageCount;
         _scanAllStallTicks = 0;
@@ -982,8 +1079,12 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
         int total = doc?.PageCount ?? 0;
 
         // Build the full figure index from whatever was scanned, store per-tab.
+        // Snapshot the analyses too, so the coverage report outlives the trim below.
         if (doc is not null && ActiveTab is { } tab)
+        {
             tab.FullScanPeekIndex = PeekIndexBuilder.Build(doc.CanonicalAnalyses, doc.PageCount);
+            tab.FullScanAnalyses = doc.CanonicalAnalyses.ToDictionary(kv => kv.Key, kv => kv.Value);
+        }
 
         // Restore the analysis window the user had before the sweep.
         _appConfig.BackgroundAnalysisWindowPages = _scanAllOriginalWindowPages;
@@ -1005,6 +1106,53 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
                 : $"Scan finished — {scanned} of {total} pages (some could not be analysed)");
     }
 
+    /// <summary>
+    /// Write a per-page analysis coverage CSV for the active document: navigable blocks, share of the
+    /// page text they cover, and low-confidence detections. Uses the Scan All snapshot when there is
+    /// one, otherwise whichever pages happen to be analysed.
+    /// </summary>
+    [RelayCommand]
+    public async Task ExportCoverageReport()
+    {
+        if (_window is null || ActiveTab is not { } tab) return;
+
+        var analyses = tab.FullScanAnalyses ?? tab.AnalysisCache;
+        if (analyses.Count == 0)
+        {
+            ShowStatusToast("No pages analysed yet — run Scan All first");
+            return;
+        }
+
+        var file = await _window.StorageProvider.SaveFilePickerAsync(new FilePickerSaveOptions
+        {
+            Title = "Export Analysis Coverage Report",
+            DefaultExtension = "csv",
+            FileTypeChoices = [new FilePickerFileType("CSV Files") { Patterns = ["*.csv"] }],
+            SuggestedFileName = Path.GetFileNameWithoutExtension(tab.FilePath) + "_coverage.csv",
+        });
+        if (file is null) return;
+
+        var outputPath = file.TryGetLocalPath() ?? file.Path.LocalPath;
+        if (outputPath is null) return;
+
+        try
+        {
+            var rows = AnalysisCoverage.Build(tab.State, analyses, _appConfig.NavigableRoles);
+            File.WriteAllText(outputPath, AnalysisCoverage.ToCsv(rows));
+
+            var flagged = rows.Where(r => r.HasGap).Select(r => r.Page + 1).ToList();
+            string scope = rows.Count < tab.PageCount ? $"{rows.Count} of {tab.PageCount}" : $"{rows.Count}";
+            ShowStatusToast(flagged.Count == 0
+                ? $"Coverage report: {scope} pages, no gaps found"
+                : $"Coverage report: {scope} pages, {flagged.Count} flagged (first: p.{flagged[0]})");
+        }
+        catch (Exception ex)
+        {
+            _logger.Error("[Coverage] Export failed", ex);
+            ShowErrorWithLog("Failed to export coverage report");
+        }
+    }
+
     private void StopScanAllTimer()
     {
         if (_scanAllTimer is not null)
@@ -1092,6 +1240,7 @@ public sealed partial class MainWindowViewModel : ObservableObject, IDisposable
 
     private void InvalidateAfterNavigation()
     {
+        ReapplyFitModeOnPageChange();
         OnPropertyChanged(nameof(ActiveTab));
         InvalidateAll();
         RequestAnimationFrame();
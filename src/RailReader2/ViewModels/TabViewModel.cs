@@ -2,6 +2,7 @@ using CommunityToolkit.Mvvm.ComponentModel;
 using RailReader.Core;
 using RailReader.Core.Models;
 using RailReader.Core.Services;
+using RailReader2.Services;
 using SkiaSharp;
 
 namespace RailReader2.ViewModels;
@@ -25,6 +26,15 @@ public sealed partial class TabViewModel : ObservableObject, IDisposable
     /// model's <c>Primary</c> for the first tab on a file; an added viewport for duplicate tabs.</summary>
     public Viewport Viewport { get; }
 
+    /// <summary>How long this view has recently waited for a page's analysis (status bar readout).</summary>
+    internal AnalysisWaitTracker AnalysisWait { get; } = new();
+
+    /// <summary>Reading pace for the status bar's progress / time-left readout.</summary>
+    internal ReadingProgress Progress { get; private set; } = new();
+
+    /// <summary>Time spent per page/block while rail-reading (Help → Reading Stats).</summary>
+    internal ReadingStats Stats { get; private set; } = new();
+
     [ObservableProperty] private string _title;
     [ObservableProperty] private int _currentPage;
     [ObservableProperty] private double _pageWidth;
@@ -35,12 +45,27 @@ public sealed partial class TabViewModel : ObservableObject, IDisposable
     [ObservableProperty] private bool _lineHighlightEnabled = true;
     [ObservableProperty] private bool _marginCropping;
 
+    /// <summary>The fit this view re-applies on page change and resize (see
+    /// <c>MainWindowViewModel.FitMode.cs</c>); <see cref="FitModePage"/> is the page it was last applied on.</summary>
+    public FitMode FitMode { get; set; }
+    internal int FitModePage { get; set; }
+
+    /// <summary>Where the rail was before a reload from disk — page, page-block index and line — until
+    /// the new copy's analysis of that page arrives and it can be put back.</summary>
+    internal (int Page, int Block, int Line)? ReloadRailSeat { get; set; }
+
     /// <summary>
     /// Full-scan figure index from a completed Scan All operation. Persists per-document
     /// so switching tabs doesn't lose the data. Null until a scan is performed.
     /// </summary>
     public PeekIndex? FullScanPeekIndex { get; set; }
 
+    /// <summary>
+    /// Analyses captured when Scan All finished, before the distant-page trim, so the coverage report
+    /// can still see every scanned page. Null until a scan is performed.
+    /// </summary>
+    public IReadOnlyDictionary<int, PageAnalysis>? FullScanAnalyses { get; set; }
+
     /// <summary>This document's linked-context portals (shell-managed sidecar). Loaded in
     /// <c>OpenDocument</c> right after annotations; follows <c>ActiveTab</c> for free. Duplicate tabs
     /// of the same PDF each hold their own set (last-writer-wins; documented, not solved in v1).</summary>
@@ -154,11 +179,31 @@ public sealed partial class TabViewModel : ObservableObject, IDisposable
     partial void OnPageWidthChanged(double value) => Viewport.PageWidth = value;
     partial void OnPageHeightChanged(double value) => Viewport.PageHeight = value;
     partial void OnDebugOverlayChanged(bool value) => State.DebugOverlay = value;
-    partial void OnPendingRailSetupChanged(bool value) => Viewport.PendingRailSetup = value;
+    partial void OnPendingRailSetupChanged(bool value)
+    {
+        Viewport.PendingRailSetup = value;
+        if (value) AnalysisWait.Begin();
+        else AnalysisWait.End();
+    }
     partial void OnLineFocusBlurChanged(bool value) => State.LineFocusBlur = value;
     partial void OnLineHighlightEnabledChanged(bool value) => State.LineHighlightEnabled = value;
     partial void OnMarginCroppingChanged(bool value) => State.MarginCropping = value;
 
+    /// <summary>Take over <paramref name="old"/>'s display prefs, fit, side-panel state and reading
+    /// history — <paramref name="old"/> being the same view before its document was reloaded.</summary>
+    internal void CarryOverFrom(TabViewModel old)
+    {
+        DebugOverlay = old.DebugOverlay;
+        LineFocusBlur = old.LineFocusBlur;
+        LineHighlightEnabled = old.LineHighlightEnabled;
+        MarginCropping = old.MarginCropping;
+        FitMode = old.FitMode;
+        ShowSidePanel = old.ShowSidePanel;
+        SidePanelWidth = old.SidePanelWidth;
+        Progress = old.Progress;
+        Stats = old.Stats;
+    }
+
     // Methods used by Views (MinimapControl, MainWindow) — act on this tab's own viewport.
     public void CenterPage(double ww, double wh) => Viewport.CenterPage(ww, wh);
     public void ClampCamera(double ww, double wh) => Viewport.ClampCamera(ww, wh);
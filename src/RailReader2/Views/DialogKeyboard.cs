@@ -6,7 +6,7 @@ namespace RailReader2.Views;
 /// <summary>
 /// Helpers for the keyboard/focus boilerplate shared by simple modal dialogs
 /// (<see cref="ConfirmUrlDialog"/>, <see cref="BookmarkNameDialog"/>,
-/// <see cref="TextNoteDialog"/>, <see cref="GoToPageDialog"/>).
+/// <see cref="TextNoteDialog"/>, <see cref="GoToPageDialog"/>, <see cref="OverwriteImagesDialog"/>).
 /// </summary>
 internal static class DialogKeyboard
 {
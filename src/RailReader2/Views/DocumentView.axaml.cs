@@ -152,18 +152,9 @@ public partial class DocumentView : UserControl, IViewportSurface
             _images = null;
             _ownsImages = false;
         }
-        // Retire this surface's freeze crops on the FreezePaneLayer's composition thread before dropping
-        // the viewport reference: a UI-thread dispose could free a raster bitmap while OnRender is still
-        // drawing it. The VM hands the crops over WITHOUT disposing; clearing the layer state first drops
-        // the layer's reference so the queued RetireImage frees them only after the clear is processed.
-        // Teardown is close/shutdown-only (never relocate), so taking the freeze here can't lose a live one.
-        if (_shared is { } fvm && _viewport is { } fvp)
-        {
-            FreezeLayer.UpdateState(EmptyFreeze);
-            foreach (var img in fvm.TakeFreezeCrops(fvp))
-                if (!FreezeLayer.TrySendMessage(new RetireImage(img)))
-                    img.Dispose();
-        }
+        // Retire this surface's freeze crops before dropping the viewport reference. Teardown is
+        // close/shutdown-only (never relocate), so taking the freeze here can't lose a live one.
+        if (_viewport is { } fvp) RetireFreezeCrops(fvp);
         // Drop references so a late event (a queued pointer / size change during the reparent or
         // close) can't reach a removed/disposed Core viewport through OwnerView → SurfaceViewport.
         Viewport.OwnerView = null;
@@ -171,6 +162,19 @@ public partial class DocumentView : UserControl, IViewportSurface
         _viewport = null;
     }
 
+    /// <summary>Retire <paramref name="vp"/>'s freeze crops on the FreezePaneLayer's composition thread:
+    /// a UI-thread dispose could free a raster bitmap while OnRender is still drawing it. The VM hands
+    /// the crops over WITHOUT disposing; clearing the layer state first drops the layer's reference so
+    /// the queued RetireImage frees them only after the clear is processed.</summary>
+    private void RetireFreezeCrops(CoreViewport vp)
+    {
+        if (_shared is not { } fvm) return;
+        FreezeLayer.UpdateState(EmptyFreeze);
+        foreach (var img in fvm.TakeFreezeCrops(vp))
+            if (!FreezeLayer.TrySendMessage(new RetireImage(img)))
+                img.Dispose();
+    }
+
     protected override void OnAttachedToVisualTree(VisualTreeAttachmentEventArgs e)
     {
         base.OnAttachedToVisualTree(e);
@@ -296,6 +300,7 @@ public partial class DocumentView : UserControl, IViewportSurface
         _viewport = tab?.Viewport;
         _images = tab?.Images;
         _ownsImages = false; // borrowing tab.Images
+        if (_viewport is { } vp) AdoptViewSize(vp);
         UpdateLayerBindings(tab);
         UpdatePagePanelSize(tab);
         Minimap.InvalidateVisual();
@@ -309,6 +314,26 @@ public partial class DocumentView : UserControl, IViewportSurface
     /// survive). Keeps this surface's own viewport / images / wake hooks; only swaps which tab supplies
     /// per-tab display prefs + model state. No-op for the primary pane, whose viewport IS the tab's own
     /// (it follows ActiveTab via <see cref="SetTab"/>, which swaps the viewport too).</summary>
+    /// <summary>Move this SECONDARY surface onto <paramref name="vp"/>, a view of a reloaded document, and
+    /// the <paramref name="tab"/> that replaced its own, keeping its camera. The old viewport's freeze
+    /// crops are retired here; removing that viewport is the caller's job.</summary>
+    internal void RebindReloaded(CoreViewport vp, TabViewModel tab)
+    {
+        if (_viewport is not { } old || ReferenceEquals(old, _tab?.Viewport)) return;
+        RetireFreezeCrops(old);
+        var camera = old.Camera;
+        var (zoom, offsetX, offsetY) = (camera.Zoom, camera.OffsetX, camera.OffsetY);
+        BindViewport(vp, new ViewportImages(vp), ownsImages: true);
+        // BindViewport centres the page; put the camera back where the reader had it.
+        vp.Camera.Zoom = zoom;
+        vp.Camera.OffsetX = offsetX;
+        vp.Camera.OffsetY = offsetY;
+        vp.ClampCamera(vp.Width, vp.Height);
+        vp.UpdateRailZoom(vp.Width, vp.Height);
+        _tab = tab;
+        UpdateAllLayers();
+    }
+
     public void RebindTab(TabViewModel newTab)
     {
         if (newTab is null || ReferenceEquals(_tab, newTab)) return;
@@ -411,6 +436,7 @@ public partial class DocumentView : UserControl, IViewportSurface
                 _shared.UnfreezeViewport(vp);
                 _shared.ShowStatusToast("Panes unfrozen — the view was resized");
             }
+            _shared.ReapplyFitMode(vp);
             UpdatePagePanelSize(_tab);
             UpdateAllLayers();
         }
@@ -418,6 +444,33 @@ public partial class DocumentView : UserControl, IViewportSurface
         // primary is sized from its own bounds once a tab binds here).
     }
 
+    /// <summary>Bring a viewport that just became this pane's up to the pane's current size. A background
+    /// tab misses every resize that happened while it was hidden, so its stored size and camera can be
+    /// stale — on a much smaller window the page may sit entirely off-screen. Mirrors
+    /// <see cref="OnViewportSizeChanged"/>, plus a re-fit when clamping alone can't bring the page back.</summary>
+    private void AdoptViewSize(CoreViewport vp)
+    {
+        if (_shared is null) return;
+        var (ww, wh) = (Viewport.Bounds.Width, Viewport.Bounds.Height);
+        if (ww <= 0 || wh <= 0) return; // not laid out yet — OnViewportSizeChanged sizes it on first layout
+
+        bool resized = vp.Width != ww || vp.Height != wh;
+        vp.SetSize(ww, wh);
+        vp.ClampCamera(ww, wh);
+        var cam = vp.Camera;
+        if (ViewportGeometry.IsPageOffScreen(cam.OffsetX, cam.OffsetY, cam.Zoom,
+                vp.PageWidth, vp.PageHeight, ww, wh))
+            vp.CenterPage(ww, wh);
+        vp.UpdateRailZoom(ww, wh);
+
+        // Same reasoning as a live resize: freeze geometry belongs to the size it was set at.
+        if (resized && _shared.IsViewportFrozen(vp))
+        {
+            _shared.UnfreezeViewport(vp);
+            _shared.ShowStatusToast("Panes unfrozen — the view was resized");
+        }
+    }
+
     private void UpdateLayerBindings(TabViewModel? tab)
     {
         if (_shared is null) return;
@@ -558,7 +611,8 @@ public partial class DocumentView : UserControl, IViewportSurface
             LineHighlightEnabled: tab?.LineHighlightEnabled ?? true,
             LinePadding: (float)vm.AppConfig.LinePadding,
             Tint: vm.AppConfig.LineHighlightTint,
-            TintOpacity: (float)vm.AppConfig.LineHighlightOpacity);
+            TintOpacity: (float)vm.AppConfig.LineHighlightOpacity,
+            JumpHints: vm.QuickJumpHintsFor(_viewport));
     }
 
     private static readonly FreezePaneRenderState EmptyFreeze =
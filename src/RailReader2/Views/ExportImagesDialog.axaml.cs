@@ -0,0 +1,58 @@
+using Avalonia.Controls;
+using Avalonia.Input;
+using Avalonia.Interactivity;
+using RailReader2.Services;
+
+namespace RailReader2.Views;
+
+/// <summary>What to export: 0-based pages, resolution, and whether to bake in the colour effect.</summary>
+public sealed record ExportImagesOptions(IReadOnlyList<int> Pages, int Dpi, bool ApplyColourEffect);
+
+public partial class ExportImagesDialog : Window
+{
+    private readonly int _pageCount;
+
+    /// <param name="currentPage">1-based page the range defaults to.</param>
+    /// <param name="colourEffectActive">Whether there is a colour effect to apply; the checkbox is
+    /// disabled otherwise.</param>
+    public ExportImagesDialog(int currentPage, int pageCount, bool colourEffectActive)
+    {
+        _pageCount = pageCount;
+        InitializeComponent();
+        PagesInput.Text = currentPage.ToString();
+        PagesInput.PlaceholderText = $"e.g. 1-5,8 (blank = all {pageCount})";
+        DpiInput.Value = PageImageExporter.DefaultDpi;
+        ColourEffectCheck.IsEnabled = colourEffectActive;
+
+        DialogKeyboard.FocusOnOpen(this, PagesInput, selectAll: true);
+        // Enter is handled here rather than by DialogKeyboard so a bad range keeps the dialog open.
+        DialogKeyboard.EnableEscEnterClose<ExportImagesOptions?>(this, cancelResult: null, confirmResult: null);
+        KeyDown += (_, e) =>
+        {
+            if (e.Key != Key.Enter) return;
+            TryConfirm();
+            e.Handled = true;
+        };
+    }
+
+    public ExportImagesDialog() : this(1, 1, false) { }
+
+    private void TryConfirm()
+    {
+        var (pages, error) = PageImageExporter.ParsePages(PagesInput.Text, _pageCount);
+        if (pages is null)
+        {
+            ErrorText.Text = error;
+            ErrorText.IsVisible = true;
+            return;
+        }
+        int dpi = (int)Math.Clamp(DpiInput.Value ?? PageImageExporter.DefaultDpi,
+            PageImageExporter.MinDpi, PageImageExporter.MaxDpi);
+        Close(new ExportImagesOptions(pages, dpi,
+            ColourEffectCheck.IsEnabled && ColourEffectCheck.IsChecked == true));
+    }
+
+    private void OnOkClick(object? sender, RoutedEventArgs e) => TryConfirm();
+
+    private void OnCancelClick(object? sender, RoutedEventArgs e) => Close(null as ExportImagesOptions);
+}
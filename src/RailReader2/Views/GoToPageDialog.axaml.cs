@@ -1,28 +1,52 @@
 using Avalonia.Controls;
+using Avalonia.Input;
 using Avalonia.Interactivity;
+using Avalonia.Media;
+using RailReader2.Services;
 
 namespace RailReader2.Views;
 
 public partial class GoToPageDialog : Window
 {
+    private static readonly IBrush InvalidBrush = new SolidColorBrush(Color.FromRgb(255, 100, 100));
+
+    private readonly int _currentPage;
     private readonly int _maxPage;
 
     public GoToPageDialog(int currentPage, int maxPage)
     {
+        _currentPage = currentPage;
         _maxPage = maxPage;
         InitializeComponent();
-        PageInput.PlaceholderText = $"Page (1–{maxPage})";
+        PageInput.PlaceholderText = $"Page (1–{maxPage}, or +/-N)";
         PageInput.Text = currentPage.ToString();
+        PageInput.TextChanged += (_, _) => PageInput.ClearValue(BorderBrushProperty);
         DialogKeyboard.FocusOnOpen(this, PageInput, selectAll: true);
-        DialogKeyboard.EnableEscEnterClose(this, cancelResult: -1, confirmResult: GetPage);
+        // Enter is handled here rather than by DialogKeyboard so invalid input keeps the dialog open.
+        DialogKeyboard.EnableEscEnterClose(this, cancelResult: -1, confirmResult: null);
+        KeyDown += (_, e) =>
+        {
+            if (e.Key != Key.Enter) return;
+            TryConfirm();
+            e.Handled = true;
+        };
     }
 
     public GoToPageDialog() : this(1, 1) { }
 
-    private int GetPage() =>
-        int.TryParse(PageInput.Text, out int page) && page >= 1 && page <= _maxPage ? page : -1;
+    /// <summary>Close with the 1-based page, or mark the field red and stay open.</summary>
+    private void TryConfirm()
+    {
+        if (PageJump.Resolve(PageInput.Text, _currentPage - 1, _maxPage) is { } page)
+        {
+            Close(page + 1);
+            return;
+        }
+        PageInput.BorderBrush = InvalidBrush;
+        PageInput.SelectAll();
+    }
 
-    private void OnOkClick(object? sender, RoutedEventArgs e) => Close(GetPage());
+    private void OnOkClick(object? sender, RoutedEventArgs e) => TryConfirm();
 
     private void OnCancelClick(object? sender, RoutedEventArgs e) => Close(-1);
 }
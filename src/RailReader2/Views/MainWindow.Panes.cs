@@ -33,6 +33,7 @@ public partial class MainWindow
         vm.CloseSurfaceRequested += OnCloseFocusedSurface;
         vm.MoveSurfaceToWindowRequested += OnMoveSurfaceToWindow;
         vm.CloseExtraSurfacesRequested += OnCloseExtraSurfaces;
+        vm.DocumentReloaded += OnDocumentReloaded;
     }
 
     // The document MODEL the extra surfaces currently belong to. Extra panes / tear-offs render
@@ -54,12 +55,43 @@ public partial class MainWindow
             OnCloseExtraSurfaces();
     }
 
+    /// <summary>A document was reloaded from disk: give each split pane / tear-off showing the old
+    /// model a fresh viewport on the new one, on the same page and camera, bound to the tab that
+    /// replaced its own. Runs before the old model is disposed. The new model becomes the surfaces'
+    /// model, so the ActiveTab notification that follows doesn't collapse them.</summary>
+    private void OnDocumentReloaded(DocumentModel old, IReadOnlyDictionary<TabViewModel, TabViewModel> replacements)
+    {
+        if (Vm is not { } vm) return;
+        var extras = new List<DocumentView>(_panes.Skip(1));
+        foreach (var win in _documentWindows)
+            if (win.HostedView is { } hosted) extras.Add(hosted);
+
+        foreach (var view in extras)
+        {
+            if (view.SurfaceViewport is not { } oldVp || !old.Viewports.Contains(oldVp)) continue;
+            if (view.BoundTab is not { } bound || !replacements.TryGetValue(bound, out var tab)) continue;
+            var fresh = tab.State;
+            var vp = fresh.AddViewport();
+            vp.CurrentPage = System.Math.Clamp(oldVp.CurrentPage, 0, fresh.PageCount - 1);
+            vp.IsLive = true;
+            vp.LoadPageBitmap();
+            view.RebindReloaded(vp, tab);
+            fresh.SubmitAnalysis(vp, vm.Controller.Worker, vm.Controller.Config.NavigableRoles);
+            bool wasFocused = ReferenceEquals(vm.Controller.FocusedViewport, oldVp);
+            old.RemoveViewport(oldVp);
+            if (wasFocused) vm.FocusSurface(view, vp);
+        }
+        if (ReferenceEquals(_surfaceModel, old) && replacements.Count > 0)
+            _surfaceModel = replacements.Values.First().State;
+    }
+
     private void TeardownPanes(MainWindowViewModel vm)
     {
         vm.SplitRightRequested -= OnSplitRight;
         vm.CloseSurfaceRequested -= OnCloseFocusedSurface;
         vm.MoveSurfaceToWindowRequested -= OnMoveSurfaceToWindow;
         vm.CloseExtraSurfacesRequested -= OnCloseExtraSurfaces;
+        vm.DocumentReloaded -= OnDocumentReloaded;
         CloseAllDocumentWindows();
     }
 
@@ -2,6 +2,8 @@ using Avalonia;
 using Avalonia.Controls;
 using Avalonia.Input;
 using Avalonia.Input.Platform;
+using Avalonia.Platform.Storage;
+using Avalonia.VisualTree;
 using RailReader.Core;
 using RailReader.Core.Models;
 using RailReader2.ViewModels;
@@ -25,9 +27,11 @@ public partial class MainWindow : Window
     // bit-identical bounds each evaluation, so this never spuriously re-frames over the user's pan/zoom).
     private (int Page, int Block, BBox Bounds)? _portalAimed;
 
-    // Fullscreen hover reveal: show threshold < hide threshold for hysteresis
+    // Fullscreen hover reveal: show threshold < hide threshold for hysteresis. The top edge reveals
+    // both the menu bar and the tab strip, so it hides further down.
     private const double FullScreenShowThreshold = 5.0;
     private const double FullScreenHideThreshold = 60.0;
+    private const double FullScreenHeaderHideThreshold = 90.0;
 
     // Throttle chrome reveal toggles. Each flip reflows the DockPanel and
     // resizes the Viewport, which in turn invalidates the page layer's GPU
@@ -39,6 +43,37 @@ public partial class MainWindow : Window
     public MainWindow()
     {
         InitializeComponent();
+        // Reading stats only count time while the reader's window is in front.
+        Activated += (_, _) => Vm?.ResumeReadingStats();
+        Deactivated += (_, _) => Vm?.PauseReadingStats();
+        SetupFileDrop();
+    }
+
+    // --- File drag-and-drop ---
+
+    private void SetupFileDrop()
+    {
+        DragDrop.SetAllowDrop(this, true);
+        AddHandler(DragDrop.DragEnterEvent, OnFileDragOver);
+        AddHandler(DragDrop.DragOverEvent, OnFileDragOver);
+        AddHandler(DragDrop.DragLeaveEvent, (_, _) => DropIndicator.IsVisible = false);
+        AddHandler(DragDrop.DropEvent, OnFileDrop);
+    }
+
+    private void OnFileDragOver(object? sender, DragEventArgs e)
+    {
+        bool files = e.DataTransfer.Contains(DataFormat.File);
+        e.DragEffects = files ? DragDropEffects.Copy : DragDropEffects.None;
+        DropIndicator.IsVisible = files;
+    }
+
+    private async void OnFileDrop(object? sender, DragEventArgs e)
+    {
+        DropIndicator.IsVisible = false;
+        if (Vm is not { } vm || e.DataTransfer.TryGetFiles() is not { } items) return;
+        e.Handled = true;
+        var paths = items.Select(i => i.TryGetLocalPath()).OfType<string>().ToList();
+        await vm.OpenDroppedFiles(paths);
     }
 
     private MainWindowViewModel? Vm => DataContext as MainWindowViewModel;
@@ -58,6 +93,7 @@ public partial class MainWindow : Window
             SetupClipboardAndToolBar(vm);
             RailToolBar.ViewModel = vm;
             RailToolBar.SyncFromConfig();
+            _ = vm.Keymap; // build now, so a bad key_bindings entry is reported at startup
 
             vm.ReadSidePanelWidth = () =>
             {
@@ -390,7 +426,7 @@ public partial class MainWindow : Window
                 break;
             case nameof(MainWindowViewModel.ShowShortcuts) when vm.ShowShortcuts:
                 vm.ShowShortcuts = false;
-                await new ShortcutsDialog { FontSize = vm.CurrentFontSize }.ShowDialog(this);
+                await new ShortcutsDialog(vm.Keymap) { FontSize = vm.CurrentFontSize }.ShowDialog(this);
                 break;
             case nameof(MainWindowViewModel.ShowAbout) when vm.ShowAbout:
                 vm.ShowAbout = false;
@@ -398,6 +434,10 @@ public partial class MainWindow : Window
                 aboutDlg.SetLogFilePath(vm.LogFilePath);
                 await aboutDlg.ShowDialog(this);
                 break;
+            case nameof(MainWindowViewModel.ShowReadingStats) when vm.ShowReadingStats:
+                vm.ShowReadingStats = false;
+                await new ReadingStatsWindow(vm) { FontSize = vm.CurrentFontSize }.ShowDialog(this);
+                break;
             case nameof(MainWindowViewModel.ShowSettings) when vm.ShowSettings:
                 vm.ShowSettings = false;
                 // Scan All temporarily overrides BackgroundAnalysisWindowPages and
@@ -485,7 +525,7 @@ public partial class MainWindow : Window
         // Persist config when toolbar hides (deferred save for slider changes)
         if (wasVisible && !shouldShow)
         {
-            Vm?.AppConfig.Save();
+            if (Vm is { } vm) Services.ConfigBackup.SaveWithBackup(vm.AppConfig);
             RailToolBar.SetJumpMode(false);
         }
         else if (shouldShow && Vm is { } v)
@@ -518,6 +558,43 @@ public partial class MainWindow : Window
     /// Shared by <see cref="OnKeyDown"/> and the tear-off document windows, which forward their keys
     /// here so a focused detached pane gets the same shortcuts (input routes through FocusedViewport).</summary>
     internal bool TryHandleKey(MainWindowViewModel vm, KeyEventArgs e)
+    {
+        if (Remap(vm, e) is { } mapped)
+        {
+            bool handled = TryHandleKeyCore(vm, mapped);
+            e.Handled = mapped.Handled;
+            return handled;
+        }
+        return TryHandleKeyCore(vm, e);
+    }
+
+    /// <summary>A user key binding (<see cref="Keymap"/>) re-expressed as the built-in key it stands
+    /// for, or null when the key isn't rebound. Never applied while typing in a text box or a quick-jump
+    /// label.</summary>
+    private KeyEventArgs? Remap(MainWindowViewModel vm, KeyEventArgs e)
+    {
+        if (vm.Keymap.Translate(e.Key, e.KeyModifiers) is not { } target
+            || vm.QuickJumpActive || IsTextInputFocused(vm)) return null;
+        return new KeyEventArgs
+        {
+            RoutedEvent = e.RoutedEvent,
+            Source = e.Source,
+            Key = target.Key,
+            KeyModifiers = target.KeyModifiers,
+            PhysicalKey = e.PhysicalKey,
+        };
+    }
+
+    /// <summary>When the search TextBox has focus, let text input keys through. Also require the
+    /// Search section to actually be the open pane, so a stale focus flag (Search collapsed while the
+    /// box held focus) can't keep swallowing nav keys. The same goes for any other side-panel text box
+    /// (the outline filter, portal labels), checked live via focus.</summary>
+    private bool IsTextInputFocused(MainWindowViewModel vm)
+        => (vm.ShowOutline && vm.IsSearchInputFocused && vm.ActivePane == SidePane.Search)
+            || StatusBar.IsEditing
+            || (vm.ShowOutline && FocusManager?.GetFocusedElement() is TextBox box && OutlinePanel.IsVisualAncestorOf(box));
+
+    private bool TryHandleKeyCore(MainWindowViewModel vm, KeyEventArgs e)
     {
         // During Scan All, only Escape is allowed (to cancel the scan); all other keys are swallowed.
         if (vm.IsScanAllActive)
@@ -540,11 +617,10 @@ public partial class MainWindow : Window
             if (e.Key == Key.Right) { vm.NavigateForward(); e.Handled = true; return true; }
         }
 
-        // When the search TextBox has focus, let text input keys through. Also require the
-        // Search section to actually be the open pane, so a stale focus flag (Search collapsed
-        // while the box held focus) can't keep swallowing nav keys.
-        bool textInputFocused = (vm.ShowOutline && vm.IsSearchInputFocused && vm.ActivePane == SidePane.Search)
-            || StatusBar.IsEditing;
+        bool textInputFocused = IsTextInputFocused(vm);
+
+        if (!textInputFocused && vm.QuickJumpActive && HandleQuickJumpKey(vm, e))
+            return true;
 
         if (!textInputFocused && HandleNavigationKey(vm, e))
             { RailToolBar.SyncState(); return true; }
@@ -604,6 +680,8 @@ public partial class MainWindow : Window
                 e.Handled = true; return true;
             case Key.G:
                 vm.ShowGoToPage = true; e.Handled = true; return true;
+            case Key.D2 or Key.NumPad2:
+                vm.FitWidth(); e.Handled = true; return true;
             case Key.E:
                 vm.ToggleAnnotationMode(); e.Handled = true; return true;
             case Key.Z when shift:
@@ -612,9 +690,10 @@ public partial class MainWindow : Window
                 vm.UndoAnnotation(); e.Handled = true; return true;
             case Key.Y:
                 vm.RedoAnnotation(); e.Handled = true; return true;
+            case Key.C when shift:
+                vm.CopyCurrentBlockText(); e.Handled = true; return true;
             case Key.C:
-                if (vm.SelectedText is not null) vm.CopySelectedText();
-                e.Handled = true; return true;
+                vm.CopySelectionOrLine(); e.Handled = true; return true;
             case Key.L:
                 vm.FireAndForget(vm.CopyBlockAsLatex(), nameof(vm.CopyBlockAsLatex));
                 e.Handled = true; return true;
@@ -631,6 +710,30 @@ public partial class MainWindow : Window
         }
     }
 
+    /// <summary>Keys while quick jump (G) is waiting for a label: digits and letters go to the label,
+    /// Escape cancels. Any other key cancels too and then does its usual job (returns false).</summary>
+    private static bool HandleQuickJumpKey(MainWindowViewModel vm, KeyEventArgs e)
+    {
+        // A bare modifier press (e.g. Shift on its way to a chord) isn't an answer either way.
+        if (e.Key is Key.LeftShift or Key.RightShift or Key.LeftCtrl or Key.RightCtrl or Key.LeftAlt or Key.RightAlt)
+            return false;
+        char? label = e.Key switch
+        {
+            >= Key.D1 and <= Key.D9 => (char)('1' + (e.Key - Key.D1)),
+            >= Key.NumPad1 and <= Key.NumPad9 => (char)('1' + (e.Key - Key.NumPad1)),
+            >= Key.A and <= Key.Z => (char)('a' + (e.Key - Key.A)),
+            _ => null,
+        };
+        if (label is { } c)
+        {
+            vm.FeedQuickJump(c);
+            e.Handled = true; return true;
+        }
+        vm.CancelQuickJump();
+        if (e.Key != Key.Escape) return false;
+        e.Handled = true; return true;
+    }
+
     /// <summary>Navigation and toggle keys — only when search is not focused. Returns true if handled.</summary>
     private bool HandleNavigationKey(MainWindowViewModel vm, KeyEventArgs e)
     {
@@ -676,10 +779,20 @@ public partial class MainWindow : Window
                 vm.NavigateBack();
                 vm.NotifyBookmarksChanged();
                 e.Handled = true; return true;
+            case Key.F when e.KeyModifiers.HasFlag(KeyModifiers.Shift):
+                vm.FitBlockWidth(); e.Handled = true; return true;
             case Key.F:
                 vm.ToggleLineFocusBlur(); e.Handled = true; return true;
             case Key.H:
                 vm.ToggleLineHighlight(); RailToolBar.UpdateToggleStates(); e.Handled = true; return true;
+            case Key.M when e.KeyModifiers.HasFlag(KeyModifiers.Shift):
+                vm.HighlightCurrentLine(); e.Handled = true; return true;
+            case Key.M:
+                vm.HighlightCurrentBlock(); e.Handled = true; return true;
+            case Key.T:
+                vm.SpeakCurrentLine(); e.Handled = true; return true;
+            case Key.G:
+                vm.BeginQuickJump(); e.Handled = true; return true;
             case Key.Z when vm.CanFreeze || vm.IsFrozen || vm.FreezeArmMode != FreezeMode.None:
                 // Freeze panes: Unfreeze if frozen, else arm a "both" placement — the pointer becomes a
                 // crossing guide; click to drop the page-wide split (rows above + columns left). The
@@ -760,6 +873,9 @@ public partial class MainWindow : Window
                 e.Handled = true; return true;
             case Key.F1:
                 vm.ShowShortcuts = true; e.Handled = true; return true;
+            case Key.F5:
+                vm.FireAndForget(vm.ReloadActiveDocument(), nameof(vm.ReloadActiveDocument));
+                e.Handled = true; return true;
             case Key.F3 when e.KeyModifiers.HasFlag(KeyModifiers.Shift):
                 vm.PreviousMatch(); e.Handled = true; return true;
             case Key.F3:
@@ -801,6 +917,18 @@ public partial class MainWindow : Window
     /// from the portal / tear-off windows too, so releasing an arrow while a floating window is focused
     /// stops its viewport's hold-to-scroll — otherwise the scroll free-runs after release.</summary>
     private bool TryHandleKeyUp(MainWindowViewModel vm, KeyEventArgs e)
+    {
+        // Rebound keys release as their built-in key, so a remapped hold-to-scroll stops too.
+        if (Remap(vm, e) is { } mapped)
+        {
+            bool handled = TryHandleKeyUpCore(vm, mapped);
+            e.Handled = mapped.Handled;
+            return handled;
+        }
+        return TryHandleKeyUpCore(vm, e);
+    }
+
+    private bool TryHandleKeyUpCore(MainWindowViewModel vm, KeyEventArgs e)
     {
         if (e.Key is Key.Left or Key.Right or Key.A or Key.D)
         {
@@ -847,10 +975,10 @@ public partial class MainWindow : Window
         var pos = e.GetPosition(this);
         bool toggled = false;
 
-        // Top edge: tab bar reveal
+        // Top edge: menu bar + tab strip reveal (kept while a menu is dropped down)
         if (!vm.ShowFullScreenHeader && pos.Y <= FullScreenShowThreshold)
         { vm.ShowFullScreenHeader = true; toggled = true; }
-        else if (vm.ShowFullScreenHeader && pos.Y > FullScreenHideThreshold)
+        else if (vm.ShowFullScreenHeader && pos.Y > FullScreenHeaderHideThreshold && !MenuBar.IsMenuOpen)
         { vm.ShowFullScreenHeader = false; toggled = true; }
 
         // Bottom edge: status bar reveal
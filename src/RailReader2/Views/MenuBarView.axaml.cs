@@ -14,6 +14,9 @@ public partial class MenuBarView : UserControl
 
     private MainWindowViewModel? Vm => _vm;
 
+    /// <summary>True while one of the menus is dropped down.</summary>
+    public bool IsMenuOpen => MainMenu.IsOpen;
+
     protected override void OnLoaded(RoutedEventArgs e)
     {
         base.OnLoaded(e);
@@ -88,6 +91,7 @@ public partial class MenuBarView : UserControl
     private void OnShowIndexPane(object? s, RoutedEventArgs e) => Vm?.ShowPane(SidePane.Index);
     private void OnShowCommentsPane(object? s, RoutedEventArgs e) => Vm?.ShowPane(SidePane.Comments);
     private void OnShowPortalsPane(object? s, RoutedEventArgs e) => Vm?.ShowPane(SidePane.Portals);
+    private void OnShowPagesPane(object? s, RoutedEventArgs e) => Vm?.ShowPane(SidePane.Pages);
     private void OnShowSearchPane(object? s, RoutedEventArgs e)
     { if (Vm is { } vm) { vm.ShowOutline = true; vm.OpenSearch(); } }
     private void OnHidePanel(object? s, RoutedEventArgs e)
@@ -143,6 +147,8 @@ public partial class MenuBarView : UserControl
     { if (Vm is { } vm) vm.ShowSettings = true; }
     private void OnShowShortcuts(object? s, RoutedEventArgs e)
     { if (Vm is { } vm) vm.ShowShortcuts = true; }
+    private void OnShowReadingStats(object? s, RoutedEventArgs e)
+    { if (Vm is { } vm) vm.ShowReadingStats = true; }
     private void OnShowAbout(object? s, RoutedEventArgs e)
     { if (Vm is { } vm) vm.ShowAbout = true; }
 
@@ -157,6 +163,8 @@ public partial class MenuBarView : UserControl
     {
         if (Vm is { } vm) vm.FireAndForget(vm.CopyBlockAsLatex(), nameof(vm.CopyBlockAsLatex));
     }
+    private void OnCopyLineText(object? s, RoutedEventArgs e) => Vm?.CopyCurrentLineText();
+    private void OnCopyBlockText(object? s, RoutedEventArgs e) => Vm?.CopyCurrentBlockText();
     private void OnCopyBlockAsMarkdown(object? s, RoutedEventArgs e)
     {
         if (Vm is { } vm) vm.FireAndForget(vm.CopyCurrentBlock(VlmService.BlockAction.Markdown), nameof(vm.CopyCurrentBlock));
@@ -177,6 +185,8 @@ public partial class MenuBarView : UserControl
     private void OnToggleJumpMode(object? s, RoutedEventArgs e) => Vm?.ToggleJumpModeExclusive();
     private void OnToggleLineFocusDim(object? s, RoutedEventArgs e) => Vm?.ToggleLineFocusBlur();
     private void OnToggleLineHighlightMenu(object? s, RoutedEventArgs e) => Vm?.ToggleLineHighlight();
+    private void OnHighlightBlock(object? s, RoutedEventArgs e) => Vm?.HighlightCurrentBlock();
+    private void OnHighlightLine(object? s, RoutedEventArgs e) => Vm?.HighlightCurrentLine();
     private void OnAddBookmark(object? s, RoutedEventArgs e)
     { if (Vm is { } vm) vm.ShowBookmarkDialog = true; }
 }
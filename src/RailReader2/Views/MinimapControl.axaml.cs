@@ -8,6 +8,7 @@ using Avalonia.Media;
 using Avalonia.Rendering.SceneGraph;
 using Avalonia.Skia;
 using RailReader.Core;
+using RailReader.Core.Models;
 using RailReader2.ViewModels;
 using SkiaSharp;
 
@@ -46,6 +47,9 @@ public partial class MinimapControl : UserControl
     private Point _dragStartGlobal;    // for stable deltas (window coords)
     private Visual? _dragSpace;        // visual the drag-start global coords are relative to
     private double _dragStartW, _dragStartH, _dragStartMR, _dragStartMB;
+    // Navigate drag that started on the viewport rectangle: the press point's offset from the
+    // rectangle's centre, kept for the whole drag so the view doesn't jump to centre on the cursor.
+    private Vector _grabOffset;
     private bool _hover;
 
     // Mipmapped GPU texture for the high-DPI source bitmap. Without this,
@@ -111,6 +115,7 @@ public partial class MinimapControl : UserControl
             PaneViewport,
             OwnerView?.SurfaceMinimapImage,
             OwnerView?.SurfacePageImage,
+            BuildBlockOverlay(PaneViewport),
             showChrome: _hover || dragging,
             drag: dragging,
             resizeCorner: ResizeCornerInside(),
@@ -118,6 +123,62 @@ public partial class MinimapControl : UserControl
             winH: win?.ClientSize.Height ?? 900));
     }
 
+    /// <summary>Page-space layout for the minimap overlay: every detected block (navigable ones
+    /// brighter) plus, while rail-reading, the current block and line. Snapshotted on the UI thread;
+    /// null until the page has been analysed.</summary>
+    private BlockOverlay? BuildBlockOverlay(Viewport? vp)
+    {
+        if (vp?.Owner is not { } doc || _vm is null
+            || !doc.TryGetAnalysis(vp.CurrentPage, vp.AnalysisParams, out var analysis))
+            return null;
+
+        var roles = _vm.Controller.Config.NavigableRoles;
+        var navigable = new List<SKRect>();
+        var other = new List<SKRect>();
+        foreach (var b in analysis.Blocks)
+            (roles.Contains(b.Role) ? navigable : other).Add(ToRect(b.BBox.X, b.BBox.Y, b.BBox.W, b.BBox.H));
+
+        SKRect? current = null, line = null;
+        if (vp.Rail is { VisualsVisible: true, CurrentNavigableBlock: { } block } rail)
+        {
+            current = ToRect(block.BBox.X, block.BBox.Y, block.BBox.W, block.BBox.H);
+            var li = rail.CurrentLineInfo;
+            line = ToRect(li.X, li.Y - li.Height / 2.0, li.Width, li.Height);
+        }
+        return new BlockOverlay(analysis, navigable, other, current, line);
+
+        static SKRect ToRect(double x, double y, double w, double h) => SKRect.Create((float)x, (float)y, (float)w, (float)h);
+    }
+
+    /// <summary>Navigable block under a minimap point, as a page-block index, or -1.</summary>
+    private int NavigableBlockAt(Point pos, Viewport vp)
+    {
+        if (vp.Owner is not { } doc || _vm is null
+            || ThumbnailGeometry.Compute(Bounds.Width, Bounds.Height, vp.PageWidth, vp.PageHeight) is not { } t
+            || !doc.TryGetAnalysis(vp.CurrentPage, vp.AnalysisParams, out var analysis))
+            return -1;
+        double pageX = (pos.X - t.X) / t.Scale;
+        double pageY = (pos.Y - t.Y) / t.Scale;
+        var roles = _vm.Controller.Config.NavigableRoles;
+        for (int i = 0; i < analysis.Blocks.Count; i++)
+        {
+            var b = analysis.Blocks[i];
+            if (roles.Contains(b.Role)
+                && pageX >= b.BBox.X && pageX <= b.BBox.X + b.BBox.W
+                && pageY >= b.BBox.Y && pageY <= b.BBox.Y + b.BBox.H)
+                return i;
+        }
+        return -1;
+    }
+
+    private sealed record BlockOverlay(PageAnalysis Analysis, List<SKRect> Navigable, List<SKRect> Other,
+        SKRect? Current, SKRect? Line)
+    {
+        // Identity is the analysis object plus the rail position — the block lists follow from it.
+        public bool SameAs(BlockOverlay? other) => other is not null
+            && ReferenceEquals(Analysis, other.Analysis) && Current == other.Current && Line == other.Line;
+    }
+
     /// <summary>
     /// The corner pointing into the visible viewport, opposite the screen
     /// edge the minimap is docked against. Default bottom-right docking
@@ -154,6 +215,10 @@ public partial class MinimapControl : UserControl
         _drag = HitResizeHandle(_dragStartLocal) ? DragMode.Resize
               : HitMoveStripe(_dragStartLocal)   ? DragMode.Move
               :                                    DragMode.Pending;
+        _grabOffset = _drag == DragMode.Pending && PaneViewport is { } pvp
+            && ViewportRect(pvp) is { } vpRect && vpRect.Contains(_dragStartLocal)
+            ? _dragStartLocal - vpRect.Center
+            : default;
 
         e.Pointer.Capture(this);
         e.Handled = true;
@@ -182,7 +247,7 @@ public partial class MinimapControl : UserControl
             if (Math.Abs(dx) < NavigateDragThreshold && Math.Abs(dy) < NavigateDragThreshold)
                 return;
             _drag = DragMode.Navigate;
-            if (PaneViewport is { } vp0) NavigateToPoint(_dragStartLocal, vp0);
+            if (PaneViewport is { } vp0) NavigateToPoint(_dragStartLocal - _grabOffset, vp0);
         }
 
         switch (_drag)
@@ -194,7 +259,7 @@ public partial class MinimapControl : UserControl
                 ApplyMove(dx, dy);
                 break;
             case DragMode.Navigate:
-                if (PaneViewport is { } vp) NavigateToPoint(local, vp);
+                if (PaneViewport is { } vp) NavigateToPoint(local - _grabOffset, vp);
                 break;
         }
         e.Handled = true;
@@ -209,7 +274,7 @@ public partial class MinimapControl : UserControl
 
         if (prev == DragMode.Pending)
         {
-            if (PaneViewport is { } vp) NavigateToPoint(pt, vp);
+            if (PaneViewport is { } vp && !TryJumpToBlock(pt, vp)) NavigateToPoint(pt, vp);
             TopLevel.GetTopLevel(this)?.Focus();
         }
         else if (prev is DragMode.Move or DragMode.Resize)
@@ -308,7 +373,7 @@ public partial class MinimapControl : UserControl
         c.MinimapHeight = Bounds.Height;
         c.MinimapMarginRight = Margin.Right;
         c.MinimapMarginBottom = Margin.Bottom;
-        c.Save();
+        Services.ConfigBackup.SaveWithBackup(c);
     }
 
     private (double W, double H) WindowClientSize()
@@ -317,6 +382,17 @@ public partial class MinimapControl : UserControl
         return top is null ? (1200, 900) : (top.ClientSize.Width, top.ClientSize.Height);
     }
 
+    /// <summary>While rail-reading, a click on a navigable block seats the rail on it (framed at the
+    /// current zoom) rather than just recentring the camera. Returns false when there's no such block.</summary>
+    private bool TryJumpToBlock(Point pos, Viewport vp)
+    {
+        if (ViewModel is not { } vm || !vp.Rail.Active) return false;
+        int index = NavigableBlockAt(pos, vp);
+        if (index < 0) return false;
+        if (OwnerView is { } ov) vm.FocusSurface(ov, vp);
+        return vm.SmoothlyFrameBlock(index, vp.Camera.Zoom);
+    }
+
     private void NavigateToPoint(Point pos, Viewport vp)
     {
         // Clicking a pane's minimap focuses that pane so input routes there (idempotent; the camera
@@ -329,8 +405,9 @@ public partial class MinimapControl : UserControl
         if (thumb is null) return;
         var t = thumb.Value;
 
-        double pageX = (pos.X - t.X) / t.Scale;
-        double pageY = (pos.Y - t.Y) / t.Scale;
+        // Clamp to the page so a drag past the thumbnail's edge pins there instead of flinging.
+        double pageX = Math.Clamp((pos.X - t.X) / t.Scale, 0, vp.PageWidth);
+        double pageY = Math.Clamp((pos.Y - t.Y) / t.Scale, 0, vp.PageHeight);
 
         var (winW, winH) = WindowClientSize();
 
@@ -350,6 +427,21 @@ public partial class MinimapControl : UserControl
         ViewModel?.RequestCameraUpdate();
     }
 
+    /// <summary>The visible region of <paramref name="vp"/> in minimap coordinates — the rectangle
+    /// the draw op paints.</summary>
+    private Rect? ViewportRect(Viewport vp)
+    {
+        if (ThumbnailGeometry.Compute(Bounds.Width, Bounds.Height, vp.PageWidth, vp.PageHeight) is not { } t)
+            return null;
+        var (winW, winH) = WindowClientSize();
+        double zoom = vp.Camera.Zoom;
+        return new Rect(
+            -vp.Camera.OffsetX / zoom * t.Scale + t.X,
+            -vp.Camera.OffsetY / zoom * t.Scale + t.Y,
+            winW / zoom * t.Scale,
+            winH / zoom * t.Scale);
+    }
+
     private readonly record struct ThumbnailGeometry(double X, double Y, double W, double H, double Scale)
     {
         public static ThumbnailGeometry? Compute(double controlW, double controlH, double pageW, double pageH)
@@ -376,6 +468,7 @@ public partial class MinimapControl : UserControl
         // Snapshot for Equals — quantised to avoid per-pixel redraws.
         private readonly SKImage? _thumbImage;
         private readonly SKImage? _primaryImage;
+        private readonly BlockOverlay? _blocks;
         private readonly int _oxQ, _oyQ, _zoomQ;
 
         [ThreadStatic] private static SKPaint? s_bgPaint;
@@ -385,6 +478,11 @@ public partial class MinimapControl : UserControl
         [ThreadStatic] private static SKPaint? s_chromeFill;
         [ThreadStatic] private static SKPaint? s_gripDot;
         [ThreadStatic] private static SKPaint? s_hashLine;
+        [ThreadStatic] private static SKPaint? s_blockFill;
+        [ThreadStatic] private static SKPaint? s_blockStroke;
+        [ThreadStatic] private static SKPaint? s_otherBlockFill;
+        [ThreadStatic] private static SKPaint? s_currentBlockStroke;
+        [ThreadStatic] private static SKPaint? s_currentLine;
 
         // Mitchell at rest for crisp thumbnails; Linear during drag for cheapness.
         private static readonly SKSamplingOptions s_samplingRest =
@@ -393,7 +491,7 @@ public partial class MinimapControl : UserControl
             new(SKFilterMode.Linear, SKMipmapMode.Linear);
 
         public MinimapDrawOperation(Rect bounds, MinimapControl control,
-            Viewport? vp, SKImage? thumbImage, SKImage? primaryImage,
+            Viewport? vp, SKImage? thumbImage, SKImage? primaryImage, BlockOverlay? blocks,
             bool showChrome, bool drag, Corner resizeCorner,
             double winW, double winH)
         {
@@ -407,6 +505,7 @@ public partial class MinimapControl : UserControl
             _winH = winH;
             _thumbImage = thumbImage;
             _primaryImage = primaryImage;
+            _blocks = blocks;
             _oxQ = (int)(vp?.Camera.OffsetX ?? 0) / 16;
             _oyQ = (int)(vp?.Camera.OffsetY ?? 0) / 16;
             _zoomQ = (int)((vp?.Camera.Zoom ?? 1.0) * 50);
@@ -423,6 +522,7 @@ public partial class MinimapControl : UserControl
             && _resizeCorner == op._resizeCorner
             && ReferenceEquals(_thumbImage, op._thumbImage)
             && ReferenceEquals(_primaryImage, op._primaryImage)
+            && (_blocks?.SameAs(op._blocks) ?? op._blocks is null)
             && _oxQ == op._oxQ
             && _oyQ == op._oyQ
             && _zoomQ == op._zoomQ
@@ -472,6 +572,9 @@ public partial class MinimapControl : UserControl
                 canvas.DrawImage(drawn, destRect, sampling);
             }
 
+            if (_blocks is { } blocks)
+                DrawBlocks(canvas, blocks, t);
+
             // Viewport indicator. Window size is captured on the UI thread at op
             // construction (see MinimapControl.Render), not resolved here.
             double winW = _winW;
@@ -511,6 +614,58 @@ public partial class MinimapControl : UserControl
             canvas.DrawRoundRect(controlRect, borderPaint);
         }
 
+        /// <summary>Layout blocks scaled into the thumbnail: navigable ones tinted, the rest faint, the
+        /// current block outlined and the current line underlined. Fixed orange tones rather than the
+        /// rail overlay palette — the thumbnail is drawn without the colour effect, so these only need
+        /// to read against a plain page.</summary>
+        private static void DrawBlocks(SKCanvas canvas, BlockOverlay blocks, ThumbnailGeometry t)
+        {
+            float scale = (float)t.Scale, ox = (float)t.X, oy = (float)t.Y;
+            SKRect Map(SKRect r) => SKRect.Create(ox + r.Left * scale, oy + r.Top * scale, r.Width * scale, r.Height * scale);
+
+            var other = s_otherBlockFill ??= new SKPaint { Color = new SKColor(128, 128, 128, 40) };
+            foreach (var r in blocks.Other)
+                canvas.DrawRect(Map(r), other);
+
+            var fill = s_blockFill ??= new SKPaint { Color = new SKColor(255, 160, 40, 45) };
+            var stroke = s_blockStroke ??= new SKPaint
+            {
+                Color = new SKColor(255, 160, 40, 140),
+                Style = SKPaintStyle.Stroke,
+                StrokeWidth = 1f,
+            };
+            foreach (var r in blocks.Navigable)
+            {
+                var m = Map(r);
+                canvas.DrawRect(m, fill);
+                canvas.DrawRect(m, stroke);
+            }
+
+            if (blocks.Current is { } cur)
+            {
+                var outline = s_currentBlockStroke ??= new SKPaint
+                {
+                    Color = new SKColor(255, 110, 0, 240),
+                    Style = SKPaintStyle.Stroke,
+                    StrokeWidth = 2f,
+                    IsAntialias = true,
+                };
+                canvas.DrawRect(Map(cur), outline);
+            }
+
+            if (blocks.Line is { } line)
+            {
+                var m = Map(line);
+                var linePaint = s_currentLine ??= new SKPaint
+                {
+                    Color = new SKColor(220, 40, 0, 240),
+                    StrokeWidth = 1.5f,
+                    IsAntialias = true,
+                };
+                canvas.DrawLine(m.Left, m.Bottom, m.Right, m.Bottom, linePaint);
+            }
+        }
+
         private static void DrawMoveStripe(SKCanvas canvas, float controlW)
         {
             const float h = (float)MoveStripeHeight;
@@ -3,13 +3,15 @@ using Avalonia.Input;
 using Avalonia.Interactivity;
 using Avalonia.Threading;
 using RailReader.Core.Models;
+using RailReader2.Services;
 using RailReader2.ViewModels;
 
 namespace RailReader2.Views;
 
 /// <summary>
 /// Outline pane — the PDF bookmark/outline tree, kept in sync with the current page
-/// (highlighting the nearest enclosing heading) and navigating on selection. Wires its
+/// (highlighting the nearest enclosing heading) and navigating on selection. The filter box narrows
+/// the tree to matching titles plus their ancestors, expanded. Wires its
 /// ViewModel subscriptions on load and tears them down on unload so it behaves correctly
 /// as a lazily-realised tab / dockable tool.
 /// </summary>
@@ -18,6 +20,11 @@ public partial class OutlineView : PaneRefreshView
     private MainWindowViewModel? _vm;
     private TabViewModel? _watchedTab;
     private bool _suppressOutlineSelection;
+    // What the tree shows: the tab's outline, or its filtered copy while the filter box has text.
+    // Rebuilt only when the outline or the query changes — page changes just move the selection.
+    private List<OutlineEntry>? _shown;
+    private List<OutlineEntry>? _shownFrom;
+    private string? _shownQuery;
 
     // True while a pointer press is in progress on the tree, so OnOutlineSelectionChanged can
     // tell a click apart from a keyboard arrow (only a click hands focus to the viewport).
@@ -109,13 +116,32 @@ public partial class OutlineView : PaneRefreshView
 
     private void UpdateOutlineSource()
     {
-        OutlineTree.ItemsSource = _vm?.ActiveTab?.Outline;
+        var outline = _vm?.ActiveTab?.Outline;
+        var query = FilterBox.Text?.Trim();
+        if (ReferenceEquals(outline, _shownFrom) && query == _shownQuery && OutlineTree.ItemsSource == _shown) return;
+        _shownFrom = outline;
+        _shownQuery = query;
+        bool filtering = outline is { Count: > 0 } && !string.IsNullOrEmpty(query);
+        _shown = filtering ? OutlineFilter.Filter(outline!, query) : outline;
+        OutlineTree.Classes.Set("filtered", filtering);
+        OutlineTree.ItemsSource = _shown;
+        NoMatchLabel.IsVisible = filtering && _shown!.Count == 0;
+        FilterBox.IsVisible = outline is { Count: > 0 };
+    }
+
+    private void OnFilterChanged(object? sender, TextChangedEventArgs e) => Refresh();
+
+    private void OnFilterKeyDown(object? sender, KeyEventArgs e)
+    {
+        if (e.Key != Key.Escape || string.IsNullOrEmpty(FilterBox.Text)) return;
+        FilterBox.Text = "";
+        e.Handled = true;
     }
 
     private void SyncOutlineToPage()
     {
         if (_vm?.ActiveTab is not { } tab) return;
-        var outline = tab.Outline;
+        var outline = _shown;
         if (outline is null || outline.Count == 0) return;
 
         int currentPage = tab.CurrentPage;
@@ -0,0 +1,25 @@
+using Avalonia.Controls;
+using Avalonia.Interactivity;
+
+namespace RailReader2.Views;
+
+/// <summary>Asked once before a multi-page image export replaces files already in the folder. Closes
+/// with true to overwrite, false to pick another folder, and null (Escape) to cancel the export.</summary>
+public partial class OverwriteImagesDialog : Window
+{
+    public OverwriteImagesDialog(IReadOnlyList<string> existing, string folder)
+    {
+        InitializeComponent();
+        Message.Text = existing.Count == 1
+            ? $"{Path.GetFileName(existing[0])} already exists in {folder}. Overwrite it?"
+            : $"{existing.Count} of the images already exist in {folder}, starting with "
+                + $"{Path.GetFileName(existing[0])}. Overwrite them?";
+        DialogKeyboard.EnableEscEnterClose<bool?>(this, cancelResult: null, confirmResult: null);
+    }
+
+    public OverwriteImagesDialog() : this(["page.png"], "") { }
+
+    private void OnOverwriteClick(object? sender, RoutedEventArgs e) => Close(true);
+
+    private void OnChooseAnotherClick(object? sender, RoutedEventArgs e) => Close(false);
+}
@@ -4,6 +4,7 @@ using Avalonia.Rendering.Composition;
 using Avalonia.Skia;
 using RailReader.Core.Models;
 using RailReader.Renderer.Skia;
+using RailReader2.Services;
 using SkiaSharp;
 
 namespace RailReader2.Views;
@@ -25,7 +26,8 @@ internal sealed record RailOverlayRenderState(
     bool LineHighlightEnabled,
     float LinePadding,
     LineHighlightTint Tint,
-    float TintOpacity);
+    float TintOpacity,
+    IReadOnlyList<QuickJumpHint>? JumpHints = null);
 
 /// <summary>
 /// Hosts a CompositionCustomVisual for the rail overlay (dim, block outline, line highlight).
@@ -60,8 +62,8 @@ internal sealed class RailOverlayVisualHandler : CompositionCustomVisualHandler
         var state = _state;
         if (state is null) return;
 
-        // No overlay content when rail is inactive and debug is off
-        if (state.CurrentBlock is null && !state.DebugOverlay) return;
+        // No overlay content when rail is inactive, debug is off and no quick jump is pending
+        if (state.CurrentBlock is null && !state.DebugOverlay && state.JumpHints is not { Count: > 0 }) return;
 
         if (context.TryGetFeature(typeof(ISkiaSharpApiLeaseFeature)) is not ISkiaSharpApiLeaseFeature leaseFeature)
             return;
@@ -104,6 +106,9 @@ internal sealed class RailOverlayVisualHandler : CompositionCustomVisualHandler
             DrawModelBadge(canvas, modelLabel);
         }
 
+        if (state.JumpHints is { Count: > 0 } hints)
+            DrawJumpHints(canvas, hints, state.Camera.ScaleX);
+
         canvas.Restore();
     }
 
@@ -137,4 +142,32 @@ internal sealed class RailOverlayVisualHandler : CompositionCustomVisualHandler
         canvas.DrawRect(bgRect, bgPaint);
         canvas.DrawText(text, originX + padX, originY + padY - metrics.Ascent, font, textPaint);
     }
+
+    /// <summary>
+    /// Quick-jump badges, one at each labelled block's top-left corner. Drawn on top of the rail
+    /// overlay and debug labels with the debug-label paints; this layer sits above the page's colour
+    /// effect, so they read the same under every effect. Sized in screen pixels (undoing the camera
+    /// zoom) so they stay small at rail zoom.
+    /// </summary>
+    private static void DrawJumpHints(SKCanvas canvas, IReadOnlyList<QuickJumpHint> hints, float zoom)
+    {
+        if (zoom <= 0) return;
+        var font = OverlayRenderer.GetDebugFont();
+        var textPaint = OverlayRenderer.GetDebugTextPaint();
+        var bgPaint = OverlayRenderer.GetDebugBgPaint();
+        var metrics = font.Metrics;
+        var lineHeight = metrics.Descent - metrics.Ascent;
+        const float padX = 4f, padY = 2f;
+
+        foreach (var hint in hints)
+        {
+            canvas.Save();
+            canvas.Translate(hint.X, hint.Y);
+            canvas.Scale(1f / zoom);
+            var width = font.MeasureText(hint.Label);
+            canvas.DrawRect(new SKRect(0, 0, width + 2 * padX, lineHeight + 2 * padY), bgPaint);
+            canvas.DrawText(hint.Label, padX, padY - metrics.Ascent, font, textPaint);
+            canvas.Restore();
+        }
+    }
 }
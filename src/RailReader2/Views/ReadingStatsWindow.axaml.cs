@@ -0,0 +1,106 @@
+using Avalonia.Controls;
+using Avalonia.Interactivity;
+using RailReader2.Services;
+using RailReader2.ViewModels;
+
+namespace RailReader2.Views;
+
+/// <summary>Help → Reading Stats: the active tab's reading time, with a bar per page read and a
+/// CSV export of the per-block breakdown.</summary>
+public partial class ReadingStatsWindow : Window
+{
+    private readonly MainWindowViewModel? _vm;
+    private readonly TabViewModel? _tab;
+    private bool _loading;
+
+    public ReadingStatsWindow(MainWindowViewModel vm)
+    {
+        _vm = vm;
+        _tab = vm.ActiveTab;
+        InitializeComponent();
+        DialogKeyboard.EnableEscEnterClose<object?>(this, null, null);
+        _loading = true;
+        IdleMinutes.Value = vm.ReadingStatsIdleMinutes;
+        _loading = false;
+        Refresh();
+    }
+
+    public ReadingStatsWindow() => InitializeComponent();
+
+    private void Refresh()
+    {
+        PageBars.Children.Clear();
+        if (_tab?.Stats is not { } stats)
+        {
+            DocumentText.Text = "No document open";
+            return;
+        }
+        stats.Flush();
+        DocumentText.Text = _tab.Title;
+        TotalText.Text = FormatSeconds(stats.TotalSeconds);
+        PagesText.Text = stats.PagesVisited.ToString();
+        LinesText.Text = stats.LinesRead.ToString();
+        PerLineText.Text = stats.SecondsPerLine is { } perLine ? $"{perLine:F1} s" : "—";
+
+        var pages = stats.PerPage();
+        if (pages.Count == 0)
+        {
+            PageBars.Children.Add(new TextBlock { Text = "Nothing read yet — zoom in to rail-read.", Opacity = 0.6 });
+            return;
+        }
+        double max = pages.Max(p => p.Seconds);
+        foreach (var (page, seconds) in pages)
+        {
+            var row = new Grid { ColumnDefinitions = new ColumnDefinitions("56,*,64") };
+            row.Children.Add(new TextBlock { Text = $"p. {page + 1}", Opacity = 0.7 });
+            var bar = new ProgressBar
+            {
+                Minimum = 0,
+                Maximum = max,
+                Value = seconds,
+                MinWidth = 0,
+                VerticalAlignment = Avalonia.Layout.VerticalAlignment.Center,
+            };
+            Grid.SetColumn(bar, 1);
+            row.Children.Add(bar);
+            var time = new TextBlock
+            {
+                Text = FormatSeconds(seconds),
+                HorizontalAlignment = Avalonia.Layout.HorizontalAlignment.Right,
+            };
+            Grid.SetColumn(time, 2);
+            row.Children.Add(time);
+            Avalonia.Automation.AutomationProperties.SetName(row, $"Page {page + 1}: {FormatSeconds(seconds)}");
+            PageBars.Children.Add(row);
+        }
+    }
+
+    /// <summary>"42 s", "3 min 05 s", "1 h 12 min".</summary>
+    private static string FormatSeconds(double seconds)
+    {
+        var t = TimeSpan.FromSeconds(Math.Round(seconds));
+        if (t.TotalHours >= 1) return $"{(int)t.TotalHours} h {t.Minutes} min";
+        if (t.TotalMinutes >= 1) return $"{t.Minutes} min {t.Seconds:00} s";
+        return $"{t.Seconds} s";
+    }
+
+    private void OnIdleMinutesChanged(object? sender, NumericUpDownValueChangedEventArgs e)
+    {
+        if (_vm is not { } vm || _loading) return;
+        vm.ReadingStatsIdleMinutes = (int)(IdleMinutes.Value ?? ReadingStats.DefaultIdleMinutes);
+    }
+
+    private async void OnExportClick(object? sender, RoutedEventArgs e)
+    {
+        if (_vm is { } vm && _tab is { } tab)
+            await vm.ExportReadingStats(tab, this);
+    }
+
+    private void OnResetClick(object? sender, RoutedEventArgs e)
+    {
+        _tab?.Stats.Reset();
+        Refresh();
+    }
+
+    private void OnCloseClick(object? sender, RoutedEventArgs e) => Close();
+}
@@ -0,0 +1,150 @@
+using Avalonia.Controls;
+using Avalonia.Input;
+using Avalonia.Interactivity;
+using RailReader2.Services;
+
+namespace RailReader2.Views;
+
+// Settings → Shortcuts: one row per Keymap action. Clicking the key button captures the next chord
+// pressed in the window, vets it with Keymap.Build (the same checks startup applies to
+// shell_prefs.json) and saves it through the view model, which puts it into effect straight away.
+public partial class SettingsWindow
+{
+    private static readonly HashSet<Key> s_modifierKeys =
+    [
+        Key.LeftCtrl, Key.RightCtrl, Key.LeftShift, Key.RightShift,
+        Key.LeftAlt, Key.RightAlt, Key.LWin, Key.RWin,
+    ];
+
+    private Keymap.KeyAction? _capturing;
+    private Button? _captureButton;
+    private object? _captureLabel;
+
+    private void InitShortcuts()
+        // Tunnel, so the focused key button doesn't take Space or Enter as a click first.
+        => AddHandler(KeyDownEvent, OnShortcutKeyDown, RoutingStrategies.Tunnel);
+
+    private void BuildShortcutRows()
+    {
+        if (Vm is not { } vm) return;
+        _capturing = null;
+        _captureButton = null;
+        ShortcutsGrid.Children.Clear();
+        ShortcutsGrid.RowDefinitions.Clear();
+        var keymap = vm.Keymap;
+        for (int i = 0; i < Keymap.Actions.Count; i++)
+        {
+            var action = Keymap.Actions[i];
+            var chord = keymap.ChordsFor(action.Name).FirstOrDefault();
+            ShortcutsGrid.RowDefinitions.Add(new RowDefinition(GridLength.Auto));
+
+            var description = new TextBlock
+            {
+                Text = action.Description,
+                TextWrapping = Avalonia.Media.TextWrapping.Wrap,
+                VerticalAlignment = Avalonia.Layout.VerticalAlignment.Center,
+            };
+            var keyButton = new Button
+            {
+                Content = ChordLabel(keymap, action, chord),
+                MinWidth = 110,
+                Margin = new Avalonia.Thickness(0, 2),
+            };
+            keyButton.Click += (_, _) => BeginCapture(action, keyButton);
+            var reset = new Button
+            {
+                Content = "Reset",
+                IsEnabled = chord is not null,
+                Margin = new Avalonia.Thickness(0, 2),
+            };
+            reset.Click += (_, _) =>
+            {
+                vm.SetKeyBinding(action.Name, null);
+                BuildShortcutRows();
+                ShowShortcutsMessage($"{action.Description}: back to {Keymap.Format(action.Default)}");
+            };
+
+            Grid.SetRow(description, i);
+            Grid.SetRow(keyButton, i);
+            Grid.SetColumn(keyButton, 2);
+            Grid.SetRow(reset, i);
+            Grid.SetColumn(reset, 4);
+            ShortcutsGrid.Children.Add(description);
+            ShortcutsGrid.Children.Add(keyButton);
+            ShortcutsGrid.Children.Add(reset);
+        }
+    }
+
+    /// <summary>The user's chord, else the default while no other binding has taken it.</summary>
+    private static string ChordLabel(Keymap keymap, Keymap.KeyAction action, KeyGesture? chord)
+        => chord is { } bound ? Keymap.Format(bound)
+            : keymap.IsTaken(action.Default) ? "(none)"
+            : Keymap.Format(action.Default);
+
+    private void BeginCapture(Keymap.KeyAction action, Button button)
+    {
+        if (_captureButton is { } earlier) earlier.Content = _captureLabel; // abandon an earlier capture
+        _capturing = action;
+        _captureButton = button;
+        _captureLabel = button.Content;
+        button.Content = "Press a key…";
+        ShowShortcutsMessage(null);
+    }
+
+    private void OnShortcutKeyDown(object? sender, KeyEventArgs e)
+    {
+        if (_capturing is not { } action || Vm is not { } vm) return;
+        if (s_modifierKeys.Contains(e.Key)) return; // wait for the rest of the chord
+        e.Handled = true;
+        if (e.Key == Key.Escape && e.KeyModifiers == KeyModifiers.None)
+        {
+            BuildShortcutRows();
+            return;
+        }
+
+        var chord = new KeyGesture(e.Key, e.KeyModifiers);
+        string text = chord.ToString();
+        if (Keymap.TryParse(text) is not { } parsed || !parsed.Equals(chord))
+        {
+            BuildShortcutRows();
+            ShowShortcutsMessage($"{Keymap.Format(chord)} can't be used as a shortcut");
+            return;
+        }
+        if (chord.Equals(action.Default))
+        {
+            vm.SetKeyBinding(action.Name, null);
+            BuildShortcutRows();
+            return;
+        }
+
+        var candidate = vm.KeyBindings
+            .Where(b => !string.Equals(b.Key, action.Name, StringComparison.OrdinalIgnoreCase))
+            .ToDictionary(b => b.Key, b => b.Value);
+        candidate[action.Name] = text; // last, so an existing owner of the chord keeps it
+        var trial = Keymap.Build(candidate);
+        string? problem = trial.Problems.FirstOrDefault(p => p.StartsWith($"{action.Name}: ", StringComparison.Ordinal));
+        if (!trial.ChordsFor(action.Name).Contains(chord))
+        {
+            BuildShortcutRows();
+            ShowShortcutsMessage(problem ?? $"{Keymap.Format(chord)} can't be used for {action.Description}");
+            return;
+        }
+        vm.SetKeyBinding(action.Name, text);
+        BuildShortcutRows();
+        ShowShortcutsMessage(problem);
+    }
+
+    private void OnResetShortcuts(object? sender, RoutedEventArgs e)
+    {
+        if (Vm is not { } vm) return;
+        vm.ResetKeyBindings();
+        BuildShortcutRows();
+        ShowShortcutsMessage("All shortcuts are back to their defaults");
+    }
+
+    private void ShowShortcutsMessage(string? message)
+    {
+        ShortcutsMessage.Text = message;
+        ShortcutsMessage.IsVisible = message is not null;
+    }
+}
@@ -54,6 +54,7 @@ public partial class SettingsWindow : Window
     public SettingsWindow()
     {
         InitializeComponent();
+        InitShortcuts();
     }
 
     protected override void OnLoaded(RoutedEventArgs e)
@@ -70,7 +71,7 @@ public partial class SettingsWindow : Window
         if (Vm is not { } vm) return;
         var c = vm.AppConfig;
         FontScale.Value = (decimal)c.UiFontScale;
-        DarkModeCheck.IsChecked = c.DarkMode;
+        ThemeCombo.SelectedIndex = (int)vm.Theme;
         MotionBlurCheck.IsChecked = c.MotionBlur;
         BlurIntensitySlider.Value = c.MotionBlurIntensity;
         ZoomThreshold.Value = (decimal)c.RailZoomThreshold;
@@ -123,6 +124,17 @@ public partial class SettingsWindow : Window
         CustomModelMappingPath.Text = _customModel.MappingPath ?? "";
         UpdateCustomModelStatus();
         PopulateBuiltinAnalyzerCombo();
+
+        AutoReloadCheck.IsChecked = vm.AutoReloadEnabled;
+        WheelActionCombo.SelectedIndex = vm.WheelScrolls ? 1 : 0;
+        WheelSensitivity.Value = (decimal)vm.WheelSensitivity;
+        InvertScrollCheck.IsChecked = vm.InvertScroll;
+        BlockHoverInfoCheck.IsChecked = vm.BlockHoverInfoEnabled;
+        SpeechCheck.IsChecked = vm.SpeechEnabled;
+        SpeechRate.Value = vm.SpeechWordsPerMinute;
+        FollowAlongCheck.IsChecked = vm.FollowAlongEnabled;
+        FollowAlongPipeName.Text = $"Pipe name: {FollowAlongServer.DefaultPipeName}";
+        BuildShortcutRows();
     }
 
     /// <summary>
@@ -289,10 +301,58 @@ public partial class SettingsWindow : Window
         target.ItemsSource = items;
     }
 
-    private void OnDarkModeChanged(object? sender, RoutedEventArgs e)
+    private void OnThemeChanged(object? sender, SelectionChangedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading || ThemeCombo.SelectedIndex < 0) return;
+        vm.Theme = (AppTheme)ThemeCombo.SelectedIndex;
+    }
+
+    private void OnAutoReloadChanged(object? sender, RoutedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading) return;
+        vm.AutoReloadEnabled = AutoReloadCheck.IsChecked == true;
+    }
+
+    private void OnWheelActionChanged(object? sender, SelectionChangedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading || WheelActionCombo.SelectedIndex < 0) return;
+        vm.WheelScrolls = WheelActionCombo.SelectedIndex == 1;
+    }
+
+    private void OnWheelSensitivityChanged(object? sender, NumericUpDownValueChangedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading) return;
+        vm.WheelSensitivity = (double)(WheelSensitivity.Value ?? 1m);
+    }
+
+    private void OnInvertScrollChanged(object? sender, RoutedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading) return;
+        vm.InvertScroll = InvertScrollCheck.IsChecked == true;
+    }
+
+    private void OnBlockHoverInfoChanged(object? sender, RoutedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading) return;
+        vm.BlockHoverInfoEnabled = BlockHoverInfoCheck.IsChecked == true;
+    }
+
+    private void OnSpeechChanged(object? sender, RoutedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading) return;
+        vm.SpeechEnabled = SpeechCheck.IsChecked == true;
+    }
+
+    private void OnSpeechRateChanged(object? sender, NumericUpDownValueChangedEventArgs e)
+    {
+        if (Vm is not { } vm || _loading) return;
+        vm.SpeechWordsPerMinute = (int)(SpeechRate.Value ?? SpeechService.DefaultWordsPerMinute);
+    }
+
+    private void OnFollowAlongChanged(object? sender, RoutedEventArgs e)
     {
         if (Vm is not { } vm || _loading) return;
-        vm.SetDarkMode(DarkModeCheck.IsChecked == true);
+        vm.FollowAlongEnabled = FollowAlongCheck.IsChecked == true;
     }
 
     private void OnMotionBlurChanged(object? sender, RoutedEventArgs e)
@@ -423,7 +483,7 @@ public partial class SettingsWindow : Window
         vm.AppConfig.BackgroundAnalysisWindowPages = defaults.BackgroundAnalysisWindowPages;
         vm.AppConfig.PageCacheRadius = defaults.PageCacheRadius;
         vm.AppConfig.UiFontScale = defaults.UiFontScale;
-        vm.SetDarkMode(defaults.DarkMode);
+        vm.Theme = defaults.DarkMode ? AppTheme.Dark : AppTheme.Light;
         vm.AppConfig.ColourEffect = defaults.ColourEffect;
         vm.Controller.SetColourEffect(defaults.ColourEffect);
         vm.AppConfig.ColourEffectIntensity = defaults.ColourEffectIntensity;
@@ -1,8 +1,40 @@
 using Avalonia.Controls;
+using Avalonia.Media;
+using RailReader2.Services;
 
 namespace RailReader2.Views;
 
 public partial class ShortcutsDialog : Window
 {
-    public ShortcutsDialog() => InitializeComponent();
+    public ShortcutsDialog() : this(Keymap.Build(null)) { }
+
+    /// <summary>The shortcuts table, with each rebindable action showing the keys in effect.</summary>
+    internal ShortcutsDialog(Keymap keymap)
+    {
+        InitializeComponent();
+        foreach (var section in ShortcutReference.Sections)
+        {
+            var grid = new Grid { ColumnDefinitions = new ColumnDefinitions("Auto,*"), Margin = new Avalonia.Thickness(8, 0) };
+            for (int i = 0; i < section.Rows.Count; i++)
+            {
+                var row = section.Rows[i];
+                grid.RowDefinitions.Add(new RowDefinition(GridLength.Auto));
+                var key = new TextBlock
+                {
+                    Text = ShortcutReference.KeysFor(row, keymap),
+                    FontFamily = new FontFamily("Consolas,monospace"),
+                    FontWeight = FontWeight.Bold,
+                    Margin = new Avalonia.Thickness(0, 2, 20, 2),
+                };
+                var description = new TextBlock { Text = ShortcutReference.DescriptionFor(row), Margin = new Avalonia.Thickness(0, 2) };
+                Grid.SetRow(key, i);
+                Grid.SetRow(description, i);
+                Grid.SetColumn(description, 1);
+                grid.Children.Add(key);
+                grid.Children.Add(description);
+            }
+            Sections.Children.Add(new TextBlock { Text = section.Title, FontWeight = FontWeight.Bold });
+            Sections.Children.Add(grid);
+        }
+    }
 }
@@ -4,6 +4,7 @@ using Avalonia.Interactivity;
 using Avalonia.Media;
 using RailReader.Core;
 using RailReader.Core.Models;
+using RailReader2.Services;
 using RailReader2.ViewModels;
 
 namespace RailReader2.Views;
@@ -55,7 +56,9 @@ public partial class StatusBarView : UserControl
             nameof(MainWindowViewModel.AutoScrollActive) or
             nameof(MainWindowViewModel.AutoScrollParked) or
             nameof(MainWindowViewModel.JumpMode) or
-            nameof(MainWindowViewModel.StatusToast))
+            nameof(MainWindowViewModel.StatusToast) or
+            nameof(MainWindowViewModel.AnalysisActivity) or
+            nameof(MainWindowViewModel.ErrorNotice))
         {
             SubscribeToTab(_subscribedVm?.ActiveTab);
             UpdateStatus();
@@ -144,22 +147,32 @@ public partial class StatusBarView : UserControl
         };
 
         IsEditing = true;
+        input.TextChanged += (_, _) => input.ClearValue(TextBox.BorderBrushProperty);
 
-        void Commit()
+        // Accepts "247" or a relative "+10" / "-5". Enter on invalid or out-of-range input marks the
+        // field red and keeps it open; clicking away just abandons it.
+        void Commit(bool keepOpenIfInvalid)
         {
             if (!IsEditing) return;
+            int current = vm.Controller.FocusedViewport?.CurrentPage ?? tab.CurrentPage;
+            var target = PageJump.Resolve(input.Text, current, tab.PageCount);
+            if (target is null && keepOpenIfInvalid)
+            {
+                input.BorderBrush = DangerBrush;
+                input.SelectAll();
+                return;
+            }
             IsEditing = false;
-            if (int.TryParse(input.Text?.Trim(), out int page))
-                vm.GoToPage(page - 1); // 1-based input → 0-based
+            if (target is { } page) vm.GoToPage(page);
             UpdateStatus();
         }
 
         input.KeyDown += (_, e) =>
         {
-            if (e.Key == Key.Enter) { Commit(); e.Handled = true; }
+            if (e.Key == Key.Enter) { Commit(keepOpenIfInvalid: true); e.Handled = true; }
             else if (e.Key == Key.Escape) { IsEditing = false; UpdateStatus(); e.Handled = true; }
         };
-        input.LostFocus += (_, _) => Commit();
+        input.LostFocus += (_, _) => Commit(keepOpenIfInvalid: false);
 
         StatusPanel.Children[idx] = input;
         input.Focus();
@@ -206,6 +219,36 @@ public partial class StatusBarView : UserControl
         input.SelectAll();
     }
 
+    private static readonly int[] ZoomPresetPercents = [50, 100, 150, 200, 300];
+
+    /// <summary>Drop-down of common zooms: fixed percentages, the two fits, and the rail threshold
+    /// (the lowest zoom at which rail mode engages).</summary>
+    private static Button MakeZoomPresetsButton(MainWindowViewModel vm)
+    {
+        var menu = new MenuFlyout();
+        foreach (int pct in ZoomPresetPercents)
+        {
+            var item = new MenuItem { Header = $"{pct}%" };
+            item.Click += (_, _) => vm.SetZoomPercent(pct);
+            menu.Items.Add(item);
+        }
+        menu.Items.Add(new Separator());
+        var fitPage = new MenuItem { Header = "Fit Page", InputGesture = new KeyGesture(Key.D0) };
+        fitPage.Click += (_, _) => vm.FitPage();
+        var fitWidth = new MenuItem { Header = "Fit Width", InputGesture = new KeyGesture(Key.D2, KeyModifiers.Control) };
+        fitWidth.Click += (_, _) => vm.FitWidth();
+        double threshold = vm.AppConfig.RailZoomThreshold;
+        var rail = new MenuItem { Header = $"Rail Threshold ({threshold * 100:F0}%)" };
+        rail.Click += (_, _) => vm.SetZoomPercent(vm.AppConfig.RailZoomThreshold * 100);
+        menu.Items.Add(fitPage);
+        menu.Items.Add(fitWidth);
+        menu.Items.Add(rail);
+
+        var btn = MakeNavButton("IconChevronDown", (_, _) => { }, "Zoom presets", "ZoomPresets");
+        btn.Flyout = menu;
+        return btn;
+    }
+
     private void AddSeparator() =>
         StatusPanel.Children.Add(new TextBlock { Text = "|", Opacity = 0.5 });
 
@@ -225,7 +268,7 @@ public partial class StatusBarView : UserControl
         var label = new TextBlock
         {
             Text = TruncateBreadcrumb(full, BreadcrumbMaxChars),
-            FontStyle = FontStyle.Italic,
+            FontStyle = Avalonia.Media.FontStyle.Italic,
             Opacity = 0.85,
             VerticalAlignment = Avalonia.Layout.VerticalAlignment.Center,
         };
@@ -248,6 +291,80 @@ public partial class StatusBarView : UserControl
         return ellipsis + leaf;
     }
 
+    /// <summary>
+    /// Layout-model status: which model is loaded (or that none is, so rail mode is on basic
+    /// navigation), whether the worker is analyzing pages right now (with how much of this document is
+    /// done), and the recent average wait for a page's analysis — tells "slow model" apart from
+    /// "slow rendering". Clicking opens Settings, where the model is chosen.
+    /// </summary>
+    private void AddAnalysisIndicator(MainWindowViewModel vm, TabViewModel tab)
+    {
+        AddSeparator();
+        TextBlock label;
+        if (vm.ActiveLayoutModelName is not { } model)
+        {
+            label = MakeBoldLabel("AI: off", AmberBrush);
+            ToolTip.SetTip(label, "No layout model loaded — rail mode uses basic navigation. Click to open Settings.");
+            Avalonia.Automation.AutomationProperties.SetName(label, "Layout model not loaded");
+        }
+        else if (vm.AnalysisActivity is { } activity)
+        {
+            label = new TextBlock
+            {
+                Text = activity.Length > 0 ? $"AI: analyzing {activity}\u2026" : "AI: analyzing\u2026",
+                FontStyle = Avalonia.Media.FontStyle.Italic,
+                Opacity = 0.7,
+                VerticalAlignment = Avalonia.Layout.VerticalAlignment.Center,
+            };
+            ToolTip.SetTip(label, activity.Length > 0
+                ? $"Layout model: {model}. Analyzing pages in the background — {activity} pages of this document done. Click to open Settings."
+                : $"Layout model: {model}. Analyzing pages in the background. Click to open Settings.");
+            Avalonia.Automation.AutomationProperties.SetName(label, $"Layout model {model}, analyzing");
+        }
+        else
+        {
+            var avg = tab.AnalysisWait.AverageMs;
+            label = new TextBlock
+            {
+                Text = avg is { } ms ? $"AI: {ms:F0} ms avg" : "AI: ready",
+                Opacity = 0.7,
+                VerticalAlignment = Avalonia.Layout.VerticalAlignment.Center,
+            };
+            ToolTip.SetTip(label, avg is null
+                ? $"Layout model: {model}. Click to open Settings."
+                : $"Layout model: {model}. Average wait for a page's analysis over the last "
+                  + $"{AnalysisWaitTracker.WindowSize} pages. Click to open Settings.");
+            Avalonia.Automation.AutomationProperties.SetName(label, $"Layout model {model}");
+        }
+        label.Cursor = new Cursor(StandardCursorType.Hand);
+        Avalonia.Automation.AutomationProperties.SetAutomationId(label, "AnalysisIndicator");
+        label.Tapped += (_, _) => vm.ShowSettings = true;
+        StatusPanel.Children.Add(label);
+    }
+
+    /// <summary>Progress through the document ("37%", plus "~25 min left" once there's a minute of
+    /// reading to estimate from) and the thin bar along the bottom edge.</summary>
+    private void AddReadingProgress(MainWindowViewModel vm)
+    {
+        if (vm.GetReadingProgress() is not { } progress) return;
+        var (fraction, remaining) = progress;
+        int pct = (int)Math.Floor(fraction * 100);
+        AddSeparator();
+        var label = new TextBlock
+        {
+            Text = remaining is { } left ? $"{pct}% · {ReadingProgress.FormatRemaining(left)}" : $"{pct}%",
+            VerticalAlignment = Avalonia.Layout.VerticalAlignment.Center,
+        };
+        ToolTip.SetTip(label, remaining is null
+            ? "Progress through the document"
+            : "Progress through the document; time left is estimated from your pace over the last few minutes");
+        Avalonia.Automation.AutomationProperties.SetName(label, $"{pct} percent read");
+        StatusPanel.Children.Add(label);
+
+        ReadingProgressBar.Value = fraction * 100;
+        ReadingProgressBar.IsVisible = true;
+    }
+
     private static TextBlock MakeBoldLabel(string text, IBrush foreground) => new()
     {
         Text = text,
@@ -261,9 +378,11 @@ public partial class StatusBarView : UserControl
         StatusPanel.Children.Clear();
         var vm = DataContext as MainWindowViewModel;
         var tab = vm?.ActiveTab;
+        ReadingProgressBar.IsVisible = false;
         if (tab is null)
         {
             StatusPanel.Children.Add(new TextBlock { Text = "No document open" });
+            AddMessages(vm);
             return;
         }
 
@@ -293,6 +412,7 @@ public partial class StatusBarView : UserControl
         StatusPanel.Children.Add(MakeNavButton("IconChevronRight", (_, _) =>
         { if (vm?.Controller.FocusedViewport is { } v) vm.GoToPage(v.CurrentPage + 1); }, "Next page (PgDn)", "NextPage"));
         AddSeparator();
+        StatusPanel.Children.Add(MakeNavButton("IconMinus", (_, _) => vm!.HandleZoomKey(false), "Zoom out (-)", "ZoomOut"));
         _zoomLabel = new TextBlock
         {
             Text = $"Zoom: {zoomPct}%",
@@ -304,7 +424,10 @@ public partial class StatusBarView : UserControl
         Avalonia.Automation.AutomationProperties.SetAutomationId(_zoomLabel, "ZoomIndicator");
         _zoomLabel.Tapped += (_, _) => BeginZoomEdit(vm!, tab);
         StatusPanel.Children.Add(_zoomLabel);
+        StatusPanel.Children.Add(MakeNavButton("IconPlus", (_, _) => vm!.HandleZoomKey(true), "Zoom in (+)", "ZoomIn"));
+        StatusPanel.Children.Add(MakeZoomPresetsButton(vm!));
 
+        AddReadingProgress(vm!);
         AddBreadcrumb(tab, curPage);
 
         if (pendingRail)
@@ -362,6 +485,31 @@ public partial class StatusBarView : UserControl
             StatusPanel.Children.Add(MakeDangerButton("IconClose", (_, _) => vm.CancelAnnotationTool(), "Cancel tool (Escape)"));
         }
 
+        AddAnalysisIndicator(vm!, tab);
+        AddMessages(vm);
+    }
+
+    /// <summary>The current error notice (red, with its action and a dismiss button, kept until
+    /// dismissed) and the passing status toast.</summary>
+    private void AddMessages(MainWindowViewModel? vm)
+    {
+        if (vm?.ErrorNotice is { } notice)
+        {
+            AddSeparator();
+            int more = vm.PendingErrorNotices;
+            var label = MakeBoldLabel(more > 0 ? $"{notice.Message} (+{more} more)" : notice.Message, DangerBrush);
+            Avalonia.Automation.AutomationProperties.SetAutomationId(label, "ErrorNotice");
+            StatusPanel.Children.Add(label);
+            if (notice.ActionLabel is { } actionLabel)
+            {
+                var action = new Button { Content = actionLabel, Padding = new Avalonia.Thickness(6, 0), MinWidth = 0 };
+                action.Click += (_, _) => vm.RunErrorAction();
+                StatusPanel.Children.Add(action);
+            }
+            StatusPanel.Children.Add(MakeDangerButton("IconClose", (_, _) => vm.DismissError(),
+                more > 0 ? "Dismiss (shows the next message)" : "Dismiss"));
+        }
+
         if (vm?.StatusToast is { } toast)
         {
             AddSeparator();
@@ -0,0 +1,264 @@
+using System.ComponentModel;
+using Avalonia.Controls;
+using Avalonia.Interactivity;
+using Avalonia.Media.Imaging;
+using Avalonia.Threading;
+using CommunityToolkit.Mvvm.ComponentModel;
+using RailReader.Core;
+using RailReader.Core.Services;
+using RailReader.Renderer.Skia;
+using RailReader2.ViewModels;
+using SkiaSharp;
+
+namespace RailReader2.Views;
+
+public sealed partial class PageThumbnailItem : ObservableObject
+{
+    public required int Page { get; init; }
+    public required double Width { get; init; }
+    public required double Height { get; init; }
+    public string Label => (Page + 1).ToString();
+    public string Tip => $"Go to page {Page + 1}";
+    [ObservableProperty] private Bitmap? _image;
+    [ObservableProperty] private bool _isCurrent;
+}
+
+/// <summary>
+/// Pages pane — a scrollable strip of page thumbnails with the current page outlined; click one to
+/// go there. The strip is virtualised: a thumbnail is rendered (at background priority) only when its
+/// row is realised (most recently requested first, so fast scrolling doesn't queue up pages already
+/// scrolled past), and at most <see cref="MaxCachedThumbnails"/> are kept, evicting the
+/// least-recently-shown off-screen ones. Rebuilt when the active tab's document changes, which
+/// includes a reload from disk.
+/// </summary>
+public partial class ThumbnailsView : PaneRefreshView
+{
+    private const double ThumbWidth = 120;
+    // Rendered at twice the display width so thumbnails stay sharp on HiDPI screens.
+    private const int RenderPixelWidth = 240;
+    private const int MaxCachedThumbnails = 150;
+
+    private MainWindowViewModel? _vm;
+    private DocumentModel? _watchedDoc;
+    private List<PageThumbnailItem> _items = [];
+    private ThumbnailRenderer? _renderer;
+    private readonly HashSet<int> _realized = [];
+    private readonly LinkedList<int> _cached = new(); // pages holding a bitmap, least recently shown first
+    private int _currentPage = -1;
+
+    public ThumbnailsView()
+    {
+        InitializeComponent();
+        ThumbnailList.ContainerPrepared += (_, e) => OnRealized(e.Index);
+        ThumbnailList.ContainerClearing += (_, e) =>
+        {
+            if (e.Container.DataContext is not PageThumbnailItem item) return;
+            _realized.Remove(item.Page);
+            _renderer?.Cancel(item.Page);
+        };
+    }
+
+    protected override void OnLoaded(RoutedEventArgs e)
+    {
+        base.OnLoaded(e);
+        Attach(DataContext as MainWindowViewModel);
+    }
+
+    protected override void OnUnloaded(RoutedEventArgs e)
+    {
+        Detach();
+        base.OnUnloaded(e);
+    }
+
+    private void Attach(MainWindowViewModel? vm)
+    {
+        Detach();
+        _vm = vm;
+        if (_vm is null) return;
+        _vm.PropertyChanged += OnVmPropertyChanged;
+        RefreshIfVisible();
+    }
+
+    private void Detach()
+    {
+        if (_vm is not null) _vm.PropertyChanged -= OnVmPropertyChanged;
+        _vm = null;
+        Clear();
+    }
+
+    private void OnVmPropertyChanged(object? sender, PropertyChangedEventArgs args)
+    {
+        if (args.PropertyName != nameof(MainWindowViewModel.ActiveTab)) return;
+        // ActiveTab is raised on every navigation: only a different document (tab switch, reload)
+        // needs new thumbnails; otherwise just move the outline.
+        if (_vm?.ActiveTab?.State != _watchedDoc)
+            RefreshIfVisible();
+        else
+            UpdateCurrentPage();
+    }
+
+    protected override void Refresh()
+    {
+        Clear();
+        if (_vm?.ActiveTab is not { } tab) return;
+
+        _watchedDoc = tab.State;
+        var pdf = tab.Pdf;
+        var items = new List<PageThumbnailItem>(tab.PageCount);
+        for (int page = 0; page < tab.PageCount; page++)
+        {
+            var (w, h) = pdf.GetPageSize(page);
+            double aspect = w > 0 ? h / w : Math.Sqrt(2);
+            items.Add(new PageThumbnailItem { Page = page, Width = ThumbWidth, Height = Math.Round(ThumbWidth * aspect) });
+        }
+        _items = items;
+        _renderer = new ThumbnailRenderer(pdf, OnRendered);
+        ThumbnailList.ItemsSource = _items;
+        UpdateCurrentPage();
+    }
+
+    private void Clear()
+    {
+        _renderer?.Dispose();
+        _renderer = null;
+        ThumbnailList.ItemsSource = null;
+        foreach (var item in _items)
+        {
+            item.Image?.Dispose();
+            item.Image = null;
+        }
+        _items = [];
+        _realized.Clear();
+        _cached.Clear();
+        _watchedDoc = null;
+        _currentPage = -1;
+    }
+
+    private void UpdateCurrentPage()
+    {
+        int page = _vm?.ActiveTab?.CurrentPage ?? -1;
+        if (page == _currentPage || page < 0 || page >= _items.Count) return;
+        if (_currentPage >= 0 && _currentPage < _items.Count) _items[_currentPage].IsCurrent = false;
+        _items[page].IsCurrent = true;
+        _currentPage = page;
+        if (IsEffectivelyVisible) ThumbnailList.ScrollIntoView(page);
+    }
+
+    private void OnRealized(int index)
+    {
+        if (index < 0 || index >= _items.Count) return;
+        _realized.Add(index);
+        if (_items[index].Image is null)
+            _renderer?.Request(index);
+        else
+            Touch(index);
+    }
+
+    private void OnRendered(int page, Bitmap? bitmap)
+    {
+        if (page >= _items.Count || _items[page].Image is not null)
+        {
+            bitmap?.Dispose();
+            return;
+        }
+        _items[page].Image = bitmap;
+        if (bitmap is null) return;
+        Touch(page);
+        Evict();
+    }
+
+    private void Touch(int page)
+    {
+        _cached.Remove(page);
+        _cached.AddLast(page);
+    }
+
+    /// <summary>Drop the least recently shown off-screen thumbnails until the cache is within bounds;
+    /// they re-render if scrolled back to.</summary>
+    private void Evict()
+    {
+        var node = _cached.First;
+        while (_cached.Count > MaxCachedThumbnails && node is not null)
+        {
+            var next = node.Next;
+            if (!_realized.Contains(node.Value))
+            {
+                var item = _items[node.Value];
+                item.Image?.Dispose();
+                item.Image = null;
+                _cached.Remove(node);
+            }
+            node = next;
+        }
+    }
+
+    private void OnThumbnailClick(object? sender, RoutedEventArgs e)
+    {
+        if (_vm is not { } vm || sender is not Button { DataContext: PageThumbnailItem item }) return;
+        vm.GoToPage(item.Page);
+        vm.RequestViewportFocus();
+    }
+
+    /// <summary>Renders requested pages one per dispatcher tick at Background priority, newest request
+    /// first. This stays on the UI thread because the tab's PDF handle is shared with the page view
+    /// and PDFium isn't safe across threads; the low priority keeps input and scrolling responsive.
+    /// Nothing is rendered or delivered after <see cref="Dispose"/>.</summary>
+    private sealed class ThumbnailRenderer(IPdfService pdf, Action<int, Bitmap?> rendered) : IDisposable
+    {
+        private readonly List<int> _pending = [];
+        private bool _scheduled;
+        private bool _disposed;
+
+        public void Request(int page)
+        {
+            _pending.Remove(page);
+            _pending.Add(page);
+            Schedule();
+        }
+
+        public void Cancel(int page) => _pending.Remove(page);
+
+        public void Dispose()
+        {
+            _disposed = true;
+            _pending.Clear();
+        }
+
+        private void Schedule()
+        {
+            if (_scheduled || _disposed || _pending.Count == 0) return;
+            _scheduled = true;
+            Dispatcher.UIThread.Post(RenderNext, DispatcherPriority.Background);
+        }
+
+        private void RenderNext()
+        {
+            _scheduled = false;
+            if (_disposed || _pending.Count == 0) return;
+            int page = _pending[^1];
+            _pending.RemoveAt(_pending.Count - 1);
+            rendered(page, Render(page));
+            Schedule();
+        }
+
+        private Bitmap? Render(int page)
+        {
+            try
+            {
+                var (w, _) = pdf.GetPageSize(page);
+                int dpi = Math.Max(10, (int)Math.Ceiling(72.0 * RenderPixelWidth / Math.Max(1, w)));
+                using var renderedPage = pdf.RenderPage(page, dpi);
+                if (renderedPage is not SkiaRenderedPage skiaPage) return null;
+                using var data = skiaPage.Bitmap.Encode(SKEncodedImageFormat.Png, 90);
+                if (data is null) return null;
+                using var stream = new MemoryStream(data.ToArray());
+                return new Bitmap(stream);
+            }
+            catch
+            {
+                // A page PDFium can't render just stays a blank placeholder.
+                return null;
+            }
+        }
+    }
+}
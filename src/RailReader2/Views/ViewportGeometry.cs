@@ -0,0 +1,21 @@
+namespace RailReader2.Views;
+
+/// <summary>
+/// Pure camera checks used when a view adopts a viewport whose camera was last set for a different
+/// view size (tab switch after the window was resized while another tab was showing). Page→screen is
+/// <c>screen = page·zoom + offset</c>, as everywhere else in the shell.
+/// </summary>
+internal static class ViewportGeometry
+{
+    /// <summary>True when no part of the page lands inside the <paramref name="viewW"/>×<paramref name="viewH"/>
+    /// view — the camera can't be rescued by a clamp alone and the page should be re-fitted. Degenerate
+    /// sizes (no page yet, no layout yet) report false: there is nothing meaningful to re-fit against.</summary>
+    public static bool IsPageOffScreen(double offsetX, double offsetY, double zoom,
+        double pageW, double pageH, double viewW, double viewH)
+    {
+        if (viewW <= 0 || viewH <= 0 || pageW <= 0 || pageH <= 0 || zoom <= 0) return false;
+        double right = offsetX + pageW * zoom;
+        double bottom = offsetY + pageH * zoom;
+        return right <= 0 || bottom <= 0 || offsetX >= viewW || offsetY >= viewH;
+    }
+}
@@ -46,11 +46,23 @@ public class ViewportPanel : Panel
     private Point _lastLinkHitTestPos = new(double.NegativeInfinity, double.NegativeInfinity);
     private const double LinkHitTestMinMoveSq = 9.0; // 3px squared
 
+    // Backdrop around the page: mid grey in the light theme, near-black in the dark one so an inverted
+    // or amber page at night isn't framed by a bright surround.
+    private static readonly IBrush LightBackdrop = new SolidColorBrush(Color.FromRgb(128, 128, 128));
+    private static readonly IBrush DarkBackdrop = new SolidColorBrush(Color.FromRgb(32, 32, 32));
+
     public ViewportPanel()
     {
         ClipToBounds = true;
         Focusable = true;
-        Background = new SolidColorBrush(Color.FromRgb(128, 128, 128));
+        Background = LightBackdrop;
+    }
+
+    protected override void OnPropertyChanged(AvaloniaPropertyChangedEventArgs change)
+    {
+        base.OnPropertyChanged(change);
+        if (change.Property == ActualThemeVariantProperty)
+            Background = ActualThemeVariant == Avalonia.Styling.ThemeVariant.Dark ? DarkBackdrop : LightBackdrop;
     }
 
     // Expose the viewport's live state (page / zoom / rail mode / current line text) to the platform
@@ -91,11 +103,11 @@ public class ViewportPanel : Panel
     {
         base.OnPointerWheelChanged(e);
         if (ViewModel is null) return;
+        ClearBlockHover(); // the zoom moves the page out from under it (and may leave rail mode)
 
-        double scrollY = e.Delta.Y * 30.0;
         var pos = e.GetPosition(this);
-        bool ctrl = e.KeyModifiers.HasFlag(KeyModifiers.Control);
-        ViewModel.HandleZoom(scrollY, pos.X, pos.Y, ctrl);
+        ViewModel.HandleWheel(e.Delta.Y, pos.X, pos.Y,
+            e.KeyModifiers.HasFlag(KeyModifiers.Control), e.KeyModifiers.HasFlag(KeyModifiers.Shift));
         e.Handled = true;
     }
 
@@ -103,6 +115,7 @@ public class ViewportPanel : Panel
     {
         base.OnPointerPressed(e);
         Focus();
+        ClearBlockHover();
 
         var point = e.GetCurrentPoint(this);
 
@@ -208,8 +221,10 @@ public class ViewportPanel : Panel
                     var (pageX, pageY) = ScreenToPage(pos);
                     bool overLink = ViewModel.IsOverLink(pageX, pageY);
                     UpdateLinkCursor(overLink);
+                    UpdateBlockHover(pageX, pageY);
                 }
             }
+            else ClearBlockHover();
             return;
         }
 
@@ -257,6 +272,33 @@ public class ViewportPanel : Panel
         // pointer (and a fresh guide) moves onto a different split pane. The move handler re-pushes it on
         // re-entry. (OnPointerMoved only clears the guide once disarmed, never on a plain cross-pane exit.)
         ClearFreezeGuide();
+        ClearBlockHover();
+    }
+
+    // Block under the pointer whose info tooltip is showing (rail mode; see DescribeBlockAt).
+    private LayoutBlock? _hoverBlock;
+
+    /// <summary>Show the info tooltip for the block under the pointer, or hide it off a block. Runs
+    /// under the link hit-test's movement gate; the tip only changes when the block does.</summary>
+    private void UpdateBlockHover(double pageX, double pageY)
+    {
+        var info = OwnerView?.SurfaceViewport is { } vp ? ViewModel?.DescribeBlockAt(vp, pageX, pageY) : null;
+        if (ReferenceEquals(info?.Block, _hoverBlock)) return;
+        // Close before re-opening so the tip for a new block appears at the pointer, not where the
+        // previous one opened.
+        ClearBlockHover();
+        if (info is not { } hovered) return;
+        _hoverBlock = hovered.Block;
+        ToolTip.SetTip(this, hovered.Text);
+        ToolTip.SetIsOpen(this, true);
+    }
+
+    private void ClearBlockHover()
+    {
+        if (_hoverBlock is null) return;
+        _hoverBlock = null;
+        ToolTip.SetIsOpen(this, false);
+        ToolTip.SetTip(this, null);
     }
 
     private bool _showingLinkCursor;
@@ -0,0 +1,103 @@
+using RailReader.Core.Models;
+using RailReader.Core.Services;
+using RailReader2.Services;
+using Xunit;
+using PageCoverage = RailReader2.Services.AnalysisCoverage.PageCoverage;
+
+namespace RailReader.Export.Tests;
+
+public class AnalysisCoverageTests
+{
+    private static readonly HashSet<BlockRole> Navigable = [BlockRole.Text, BlockRole.Title];
+
+    [Fact]
+    public void ForPage_CountsTextInsideNavigableBlocksOnly()
+    {
+        // Ten glyphs across x 0..100; the text block holds the first five, the figure the rest.
+        var text = MakePageText("abcdefghij", 0, 10, 100, 30);
+        var page = Page(
+            Block(BlockRole.Text, 0, 5, 48, 30, confidence: 0.9f),
+            Block(BlockRole.Figure, 52, 5, 48, 30, confidence: 0.3f));
+
+        var c = AnalysisCoverage.ForPage(0, page, text, Navigable);
+
+        Assert.Equal(1, c.NavigableBlocks);
+        Assert.Equal(1, c.LowConfidenceBlocks);
+        Assert.Equal(10, c.TextChars);
+        Assert.Equal(5, c.CoveredChars);
+        Assert.Equal(0.5, c.TextCoverage, precision: 6);
+        Assert.True(c.HasGap);
+    }
+
+    [Fact]
+    public void ForPage_FullyCoveredPageHasNoGap()
+    {
+        var text = MakePageText("abcdefghij", 0, 10, 100, 30);
+        var page = Page(Block(BlockRole.Text, 0, 5, 100, 30, confidence: 0.9f));
+
+        var c = AnalysisCoverage.ForPage(0, page, text, Navigable);
+
+        Assert.Equal(1.0, c.TextCoverage, precision: 6);
+        Assert.False(c.HasGap);
+    }
+
+    [Fact]
+    public void ForPage_TextWithNoNavigableBlockIsFlagged()
+    {
+        var text = MakePageText("abcdefghij", 0, 10, 100, 30);
+        var page = Page();
+
+        var c = AnalysisCoverage.ForPage(0, page, text, Navigable);
+
+        Assert.Equal(0, c.NavigableBlocks);
+        Assert.Equal(0.0, c.TextCoverage, precision: 6);
+        Assert.True(c.HasGap);
+    }
+
+    [Fact]
+    public void TextCoverage_EmptyPageCountsAsCovered()
+    {
+        var c = new PageCoverage(3, NavigableBlocks: 0, LowConfidenceBlocks: 0, TextChars: 0, CoveredChars: 0);
+
+        Assert.Equal(1.0, c.TextCoverage);
+        Assert.False(c.HasGap);
+    }
+
+    [Fact]
+    public void TextCoverage_ClampsOverlappingBlocks()
+    {
+        var c = new PageCoverage(0, NavigableBlocks: 2, LowConfidenceBlocks: 0, TextChars: 10, CoveredChars: 14);
+
+        Assert.Equal(1.0, c.TextCoverage);
+    }
+
+    [Fact]
+    public void ToCsv_WritesOneBasedPagesWithInvariantNumbers()
+    {
+        var csv = AnalysisCoverage.ToCsv(
+        [
+            new PageCoverage(0, NavigableBlocks: 4, LowConfidenceBlocks: 1, TextChars: 200, CoveredChars: 150),
+            new PageCoverage(1, NavigableBlocks: 6, LowConfidenceBlocks: 0, TextChars: 100, CoveredChars: 100),
+        ]);
+
+        var lines = csv.Split('\n', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries);
+        Assert.Equal("page,navigable_blocks,text_coverage,low_confidence_blocks,text_chars,flagged", lines[0]);
+        Assert.Equal("1,4,0.750,1,200,yes", lines[1]);
+        Assert.Equal("2,6,1.000,0,100,no", lines[2]);
+    }
+
+    private static PageAnalysis Page(params LayoutBlock[] blocks)
+        => new() { PageWidth = 200, PageHeight = 100, Blocks = [.. blocks] };
+
+    private static LayoutBlock Block(BlockRole role, float x, float y, float w, float h, float confidence)
+        => new() { Role = role, Confidence = confidence, BBox = new BBox(x, y, w, h) };
+
+    private static PageText MakePageText(string text, float x, float y, float right, float bottom)
+    {
+        float charWidth = (right - x) / Math.Max(text.Length, 1);
+        var boxes = new List<CharBox>();
+        for (int i = 0; i < text.Length; i++)
+            boxes.Add(new CharBox(i, x + i * charWidth, y, x + (i + 1) * charWidth, bottom));
+        return new PageText(text, boxes);
+    }
+}
@@ -0,0 +1,53 @@
+using System.Diagnostics;
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class AnalysisWaitTrackerTests
+{
+    private static long Ms(double ms) => (long)(ms * Stopwatch.Frequency / 1000.0);
+
+    [Fact]
+    public void AverageMs_NullUntilAWaitCompletes()
+    {
+        var t = new AnalysisWaitTracker();
+        Assert.Null(t.AverageMs);
+        t.Begin(Ms(0));
+        Assert.Null(t.AverageMs);
+        t.End(Ms(400));
+        Assert.Equal(400, t.AverageMs!.Value, precision: 3);
+    }
+
+    [Fact]
+    public void End_WithoutBegin_IsIgnored()
+    {
+        var t = new AnalysisWaitTracker();
+        t.End(Ms(100));
+        Assert.Null(t.AverageMs);
+    }
+
+    [Fact]
+    public void Begin_WhilePending_KeepsOriginalStart()
+    {
+        var t = new AnalysisWaitTracker();
+        t.Begin(Ms(0));
+        t.Begin(Ms(300));
+        t.End(Ms(500));
+        Assert.Equal(500, t.AverageMs!.Value, precision: 3);
+    }
+
+    [Fact]
+    public void AverageMs_OnlyCoversTheLastWindow()
+    {
+        var t = new AnalysisWaitTracker();
+        long now = 0;
+        // One slow outlier, then a full window of fast waits pushes it out.
+        t.Begin(now); now += Ms(5000); t.End(now);
+        for (int i = 0; i < AnalysisWaitTracker.WindowSize; i++)
+        {
+            t.Begin(now); now += Ms(200); t.End(now);
+        }
+        Assert.Equal(200, t.AverageMs!.Value, precision: 3);
+    }
+}
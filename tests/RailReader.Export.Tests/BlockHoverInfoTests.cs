@@ -0,0 +1,29 @@
+using RailReader.Core.Models;
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class BlockHoverInfoTests
+{
+    [Fact]
+    public void Describe_NamesModelClassWithRoleAndOneBasedOrder()
+    {
+        var block = new LayoutBlock { Role = BlockRole.Heading, ClassId = 1, Confidence = 0.874f, Order = 3 };
+
+        var text = BlockHoverInfo.Describe(block, ["text", "paragraph_title"]);
+
+        Assert.Equal("paragraph_title (Heading)\n87% confidence · reading order 4", text);
+    }
+
+    [Fact]
+    public void Describe_FallsBackToRoleWithoutClassTableOrWhenNamesMatch()
+    {
+        var block = new LayoutBlock { Role = BlockRole.Text, ClassId = 0, Confidence = 0.5f, Order = 0 };
+
+        Assert.StartsWith("Text\n", BlockHoverInfo.Describe(block, null));
+        Assert.StartsWith("Text\n", BlockHoverInfo.Describe(block, ["text"]));
+        var outOfRange = new LayoutBlock { Role = BlockRole.Text, ClassId = 7, Confidence = 0.5f, Order = 0 };
+        Assert.StartsWith("Text\n", BlockHoverInfo.Describe(outOfRange, ["text"]));
+    }
+}
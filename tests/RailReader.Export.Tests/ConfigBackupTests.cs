@@ -0,0 +1,48 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public sealed class ConfigBackupTests : IDisposable
+{
+    private readonly string _dir = Directory.CreateTempSubdirectory("railreader-config-").FullName;
+    private string ConfigPath => Path.Combine(_dir, "config.json");
+    private string BackupPath => ConfigPath + ".bak";
+
+    public void Dispose() => Directory.Delete(_dir, recursive: true);
+
+    [Fact]
+    public void ConfigTruncatedMidWrite_IsRestoredFromTheCopyTakenBeforeTheSave()
+    {
+        const string good = "{\"dark_mode\": true, \"rail_zoom_threshold\": 3.0}";
+        File.WriteAllText(ConfigPath, good);
+        ConfigBackup.Backup(ConfigPath);           // before the save…
+        File.WriteAllText(ConfigPath, "{\"dark_mo"); // …which crashed half-way
+
+        Assert.True(ConfigBackup.RestoreIfUnreadable(ConfigPath));
+        Assert.Equal(good, File.ReadAllText(ConfigPath));
+    }
+
+    [Fact]
+    public void Backup_NeverOverwritesTheGoodCopyWithABrokenFile()
+    {
+        File.WriteAllText(ConfigPath, "{\"dark_mode\": true}");
+        ConfigBackup.Backup(ConfigPath);
+        File.WriteAllText(ConfigPath, "");
+        ConfigBackup.Backup(ConfigPath);
+
+        Assert.Equal("{\"dark_mode\": true}", File.ReadAllText(BackupPath));
+    }
+
+    [Fact]
+    public void ReadableOrMissingConfig_IsLeftAlone()
+    {
+        File.WriteAllText(BackupPath, "{\"dark_mode\": true}");
+        Assert.False(ConfigBackup.RestoreIfUnreadable(ConfigPath));
+        Assert.False(File.Exists(ConfigPath));
+
+        File.WriteAllText(ConfigPath, "{\"dark_mode\": false}");
+        Assert.False(ConfigBackup.RestoreIfUnreadable(ConfigPath));
+        Assert.Equal("{\"dark_mode\": false}", File.ReadAllText(ConfigPath));
+    }
+}
@@ -0,0 +1,81 @@
+using System.Text.Json;
+using RailReader2.Services;
+using Xunit;
+using GotoRequest = RailReader2.Services.FollowAlongProtocol.GotoRequest;
+
+namespace RailReader.Export.Tests;
+
+public class FollowAlongProtocolTests
+{
+    [Theory]
+    [InlineData("""{"goto": {"page": 12, "block": 3, "line": 5}}""", 12, 3, 5)]
+    [InlineData("""{"goto": {"page": 0}}""", 0, null, null)]
+    [InlineData("""{"goto": {"page": 4, "block": 2}}""", 4, 2, null)]
+    [InlineData("""{"goto": {"page": 4, "block": null}}""", 4, null, null)]
+    [InlineData("""{"goto": {"page": 1, "block": 0, "line": 0}, "extra": true}""", 1, 0, 0)]
+    public void TryParseGoto_AcceptsValidRequests(string json, int page, int? block, int? line)
+    {
+        Assert.True(FollowAlongProtocol.TryParseGoto(json, out var request, out _));
+        Assert.Equal(new GotoRequest(page, block, line), request);
+    }
+
+    [Theory]
+    [InlineData("not json")]
+    [InlineData("""{"goto": {"page": 1""")]
+    [InlineData("[1, 2, 3]")]
+    [InlineData("""{"jump": {"page": 1}}""")]
+    [InlineData("""{"goto": 12}""")]
+    [InlineData("""{"goto": {}}""")]
+    [InlineData("""{"goto": {"page": -1}}""")]
+    [InlineData("""{"goto": {"page": 1.5}}""")]
+    [InlineData("""{"goto": {"page": "3"}}""")]
+    [InlineData("""{"goto": {"page": 99999999999}}""")]
+    [InlineData("""{"goto": {"page": 1, "line": 2}}""")]
+    public void TryParseGoto_RejectsMalformedInput(string json)
+    {
+        Assert.False(FollowAlongProtocol.TryParseGoto(json, out _, out var error));
+        Assert.False(string.IsNullOrEmpty(error));
+    }
+
+    [Fact]
+    public void TryParseGoto_RejectsOversizedMessageUnparsed()
+    {
+        var json = "{\"goto\": {\"page\": 1}, \"pad\": \"" + new string('x', FollowAlongProtocol.MaxMessageLength) + "\"}";
+        Assert.False(FollowAlongProtocol.TryParseGoto(json, out _, out var error));
+        Assert.Equal("message too long", error);
+    }
+
+    [Fact]
+    public void FormatPosition_RoundTripsFieldsAndText()
+    {
+        using var doc = JsonDocument.Parse(FollowAlongProtocol.FormatPosition(7, 2, 4, "a \"quoted\" line"));
+        var pos = doc.RootElement.GetProperty("position");
+        Assert.Equal(7, pos.GetProperty("page").GetInt32());
+        Assert.Equal(2, pos.GetProperty("block").GetInt32());
+        Assert.Equal(4, pos.GetProperty("line").GetInt32());
+        Assert.Equal("a \"quoted\" line", pos.GetProperty("text").GetString());
+    }
+
+    [Fact]
+    public void FormatPosition_OmitsEmptyText()
+    {
+        using var doc = JsonDocument.Parse(FollowAlongProtocol.FormatPosition(0, 0, 0, ""));
+        Assert.False(doc.RootElement.GetProperty("position").TryGetProperty("text", out _));
+    }
+
+    [Fact]
+    public void FormatPage_HasOnlyThePage()
+    {
+        using var doc = JsonDocument.Parse(FollowAlongProtocol.FormatPage(3));
+        var pos = doc.RootElement.GetProperty("position");
+        Assert.Equal(3, pos.GetProperty("page").GetInt32());
+        Assert.False(pos.TryGetProperty("block", out _));
+    }
+
+    [Fact]
+    public void Messages_AreSingleLine()
+    {
+        Assert.DoesNotContain('\n', FollowAlongProtocol.FormatPosition(1, 2, 3, "two\nlines"));
+        Assert.DoesNotContain('\n', FollowAlongProtocol.FormatError("bad\nrequest"));
+    }
+}
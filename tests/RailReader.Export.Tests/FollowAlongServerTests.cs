@@ -0,0 +1,96 @@
+using System.IO.Pipes;
+using System.Text.Json;
+using RailReader.Core;
+using RailReader2.Services;
+using Xunit;
+using GotoRequest = RailReader2.Services.FollowAlongProtocol.GotoRequest;
+
+namespace RailReader.Export.Tests;
+
+/// <summary>Drives a real <see cref="FollowAlongServer"/> over its named pipe with a test client.</summary>
+public sealed class FollowAlongServerTests : IDisposable
+{
+    private static readonly TimeSpan Timeout = TimeSpan.FromSeconds(10);
+
+    private readonly string _pipeName = $"railreader2-test-{Guid.NewGuid():N}";
+    private readonly TaskCompletionSource<GotoRequest> _received =
+        new(TaskCreationOptions.RunContinuationsAsynchronously);
+    private readonly FollowAlongServer _server;
+
+    public FollowAlongServerTests()
+    {
+        _server = new FollowAlongServer(_pipeName, r => _received.TrySetResult(r), RailReaderLogging.Logger);
+        _server.Start();
+    }
+
+    public void Dispose() => _server.Dispose();
+
+    private async Task<(NamedPipeClientStream Pipe, StreamReader Reader, StreamWriter Writer)> ConnectAsync()
+    {
+        var pipe = new NamedPipeClientStream(".", _pipeName, PipeDirection.InOut,
+            PipeOptions.Asynchronous | PipeOptions.CurrentUserOnly);
+        await pipe.ConnectAsync(Timeout);
+        var reader = new StreamReader(pipe);
+        var writer = new StreamWriter(pipe) { NewLine = "\n", AutoFlush = true };
+        return (pipe, reader, writer);
+    }
+
+    [Fact]
+    public async Task GotoRequest_ReachesCallback()
+    {
+        var (pipe, _, writer) = await ConnectAsync();
+        await using (pipe)
+        {
+            await writer.WriteLineAsync("""{"goto": {"page": 12, "block": 3, "line": 5}}""");
+            var request = await _received.Task.WaitAsync(Timeout);
+            Assert.Equal(new GotoRequest(12, 3, 5), request);
+        }
+    }
+
+    [Fact]
+    public async Task MalformedInput_IsAnsweredWithErrorAndConnectionSurvives()
+    {
+        var (pipe, reader, writer) = await ConnectAsync();
+        await using (pipe)
+        {
+            await writer.WriteLineAsync("{ not json");
+            var reply = await reader.ReadLineAsync().WaitAsync(Timeout);
+            using (var doc = JsonDocument.Parse(reply!))
+                Assert.True(doc.RootElement.TryGetProperty("error", out _));
+
+            // Same connection still serves valid requests afterwards.
+            await writer.WriteLineAsync("""{"goto": {"page": 2}}""");
+            Assert.Equal(new GotoRequest(2, null, null), await _received.Task.WaitAsync(Timeout));
+        }
+    }
+
+    [Fact]
+    public async Task PublishedPosition_ReachesClient_AndIsReplayedOnConnect()
+    {
+        // Published before anyone connects: replayed as the first message.
+        _server.PublishPosition(FollowAlongProtocol.FormatPage(4));
+        var (pipe, reader, _) = await ConnectAsync();
+        await using (pipe)
+        {
+            Assert.Equal(FollowAlongProtocol.FormatPage(4), await reader.ReadLineAsync().WaitAsync(Timeout));
+
+            var next = FollowAlongProtocol.FormatPosition(4, 1, 0, "First line");
+            _server.PublishPosition(next);
+            Assert.Equal(next, await reader.ReadLineAsync().WaitAsync(Timeout));
+        }
+    }
+
+    [Fact]
+    public async Task ServerAcceptsNewClientAfterDisconnect()
+    {
+        var (first, _, _) = await ConnectAsync();
+        await first.DisposeAsync();
+
+        var (pipe, _, writer) = await ConnectAsync();
+        await using (pipe)
+        {
+            await writer.WriteLineAsync("""{"goto": {"page": 9}}""");
+            Assert.Equal(new GotoRequest(9, null, null), await _received.Task.WaitAsync(Timeout));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public sealed class JsonSidecarTests : IDisposable
+{
+    private readonly string _dir = Directory.CreateTempSubdirectory("railreader-sidecar-").FullName;
+    private string PrefsPath => Path.Combine(_dir, "shell_prefs.json");
+
+    public void Dispose() => Directory.Delete(_dir, recursive: true);
+
+    private void Save(ShellPreferences prefs)
+        => JsonSidecar.Save(PrefsPath, prefs, ShellPreferencesJsonContext.Default.ShellPreferences);
+
+    private ShellPreferences Load()
+        => JsonSidecar.Load(PrefsPath, ShellPreferencesJsonContext.Default.ShellPreferences,
+            static () => new ShellPreferences { ReadingStatsIdleMinutes = -1 });
+
+    [Fact]
+    public void Save_KeepsPreviousVersionAsBackup_AndLeavesNoTempFile()
+    {
+        Save(new ShellPreferences { ReadingStatsIdleMinutes = 3 });
+        Save(new ShellPreferences { ReadingStatsIdleMinutes = 7 });
+
+        Assert.Equal(7, Load().ReadingStatsIdleMinutes);
+        Assert.Contains("\"reading_stats_idle_minutes\": 3", File.ReadAllText(JsonSidecar.BackupPath(PrefsPath)));
+        Assert.False(File.Exists(PrefsPath + ".tmp"));
+    }
+
+    [Fact]
+    public void Load_CorruptedFileWithValidBackup_RecoversFromBackup()
+    {
+        Save(new ShellPreferences { ReadingStatsIdleMinutes = 3 });
+        Save(new ShellPreferences { ReadingStatsIdleMinutes = 7 });
+        File.WriteAllText(PrefsPath, "{\"reading_stats_idle_mi"); // truncated mid-write
+
+        Assert.Equal(3, Load().ReadingStatsIdleMinutes);
+    }
+
+    [Fact]
+    public void Load_CorruptedFileWithoutBackup_UsesFallback()
+    {
+        File.WriteAllText(PrefsPath, "");
+
+        Assert.Equal(-1, Load().ReadingStatsIdleMinutes);
+    }
+
+    [Fact]
+    public void Load_MissingFile_UsesFallbackEvenWithBackup()
+    {
+        Save(new ShellPreferences { ReadingStatsIdleMinutes = 3 });
+        Save(new ShellPreferences { ReadingStatsIdleMinutes = 7 });
+        File.Delete(PrefsPath); // deleting the file is how a user resets it
+
+        Assert.Equal(-1, Load().ReadingStatsIdleMinutes);
+    }
+}
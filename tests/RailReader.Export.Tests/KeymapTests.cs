@@ -0,0 +1,56 @@
+using Avalonia.Input;
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class KeymapTests
+{
+    [Fact]
+    public void BoundChord_TranslatesToTheBuiltInKey()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["next_line"] = "K", ["go_to_page"] = "Ctrl+J" });
+
+        Assert.Equal(new KeyGesture(Key.Down), keymap.Translate(Key.K, KeyModifiers.None));
+        Assert.Equal(new KeyGesture(Key.G, KeyModifiers.Control), keymap.Translate(Key.J, KeyModifiers.Control));
+        Assert.Null(keymap.Translate(Key.K, KeyModifiers.Shift));
+        Assert.Null(keymap.Translate(Key.Down, KeyModifiers.None));
+        Assert.Empty(keymap.Problems);
+    }
+
+    [Fact]
+    public void UnknownActionsAndBadKeys_AreReportedAndSkipped()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["fly"] = "K", ["next_line"] = "Ctrl+Nope" });
+
+        Assert.Equal(2, keymap.Problems.Count);
+        Assert.Empty(keymap.Bound);
+    }
+
+    [Fact]
+    public void ChordBoundTwice_KeepsTheFirst()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["next_line"] = "K", ["next_page"] = "K" });
+
+        Assert.Single(keymap.Bound);
+        Assert.Single(keymap.Problems);
+    }
+
+    [Fact]
+    public void ShadowingAnotherActionsDefault_IsAllowedButReported()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["scroll_backward"] = "J" });
+
+        Assert.Equal(new KeyGesture(Key.Left), keymap.Translate(Key.J, KeyModifiers.None));
+        Assert.Contains("jump mode", Assert.Single(keymap.Problems));
+    }
+
+    [Fact]
+    public void BindingAnActionToItsOwnDefault_IsANoOp()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["next_line"] = "Down" });
+
+        Assert.Empty(keymap.Bound);
+        Assert.Empty(keymap.Problems);
+    }
+}
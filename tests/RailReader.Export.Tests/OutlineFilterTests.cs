@@ -0,0 +1,61 @@
+using RailReader.Core.Models;
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class OutlineFilterTests
+{
+    private static OutlineEntry Entry(string title, int page, params OutlineEntry[] children)
+        => new() { Title = title, Page = page, Children = [.. children] };
+
+    private static int Count(IEnumerable<OutlineEntry> entries) => entries.Sum(e => 1 + Count(e.Children));
+
+    private static readonly List<OutlineEntry> Book =
+    [
+        Entry("Introduction", 0),
+        Entry("Methods", 4,
+            Entry("Sampling", 5),
+            Entry("Regression models", 8,
+                Entry("Linear models", 8),
+                Entry("Mixed models", 11))),
+        Entry("Results", 15),
+    ];
+
+    [Fact]
+    public void Filter_KeepsMatchesWithTheirAncestors()
+    {
+        var result = OutlineFilter.Filter(Book, "MIXED");
+
+        var methods = Assert.Single(result);
+        Assert.Equal("Methods", methods.Title);
+        var regression = Assert.Single(methods.Children);
+        Assert.Equal("Regression models", regression.Title);
+        var mixed = Assert.Single(regression.Children);
+        Assert.Equal(("Mixed models", (int?)11), (mixed.Title, mixed.Page));
+    }
+
+    [Fact]
+    public void Filter_PrunesNonMatchingChildrenOfAMatch()
+    {
+        var result = OutlineFilter.Filter(Book, "models");
+
+        var regression = Assert.Single(Assert.Single(result).Children);
+        Assert.Equal(new[] { "Linear models", "Mixed models" }, regression.Children.Select(c => c.Title));
+        Assert.Equal(4, Count(result));
+    }
+
+    [Fact]
+    public void Filter_BlankQueryReturnsEverythingAndNoMatchReturnsNothing()
+    {
+        Assert.Equal(7, Count(OutlineFilter.Filter(Book, "  ")));
+        Assert.Empty(OutlineFilter.Filter(Book, "appendix"));
+    }
+
+    [Fact]
+    public void Filter_LeavesTheSourceOutlineUntouched()
+    {
+        OutlineFilter.Filter(Book, "linear");
+        Assert.Equal(2, Book[1].Children[1].Children.Count);
+    }
+}
@@ -0,0 +1,64 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class PageImageExporterTests
+{
+    [Fact]
+    public void ParsePages_RangesAndSinglesBecomeSortedDistinctZeroBased()
+    {
+        var (pages, error) = PageImageExporter.ParsePages(" 8, 1-3 ,2, 5-4", 10);
+        Assert.Null(error);
+        Assert.Equal(new[] { 0, 1, 2, 3, 4, 7 }, pages);
+    }
+
+    [Fact]
+    public void ParsePages_BlankMeansEveryPage()
+    {
+        var (pages, error) = PageImageExporter.ParsePages("  ", 3);
+        Assert.Null(error);
+        Assert.Equal(new[] { 0, 1, 2 }, pages);
+    }
+
+    [Theory]
+    [InlineData("0")]
+    [InlineData("4-12")]
+    [InlineData("abc")]
+    [InlineData("1-2-3")]
+    [InlineData("-3")]
+    [InlineData(",")]
+    public void ParsePages_RejectsMalformedOrOutOfRange(string text)
+    {
+        var (pages, error) = PageImageExporter.ParsePages(text, 10);
+        Assert.Null(pages);
+        Assert.False(string.IsNullOrEmpty(error));
+    }
+
+    [Fact]
+    public void FileNameFor_PadsToThePageCountWidth()
+    {
+        Assert.Equal("paper_page_007.png", PageImageExporter.FileNameFor("/docs/paper.pdf", 6, 120));
+        Assert.Equal("book_page_0042.png", PageImageExporter.FileNameFor("book.pdf", 41, 1500));
+    }
+
+    [Fact]
+    public void ExistingOutputs_ListsOnlyTheFilesTheExportWouldReplace()
+    {
+        var dir = Directory.CreateTempSubdirectory("railreader-export-").FullName;
+        try
+        {
+            File.WriteAllText(Path.Combine(dir, "paper_page_002.png"), "");
+            File.WriteAllText(Path.Combine(dir, "paper_page_009.png"), ""); // not being exported
+            File.WriteAllText(Path.Combine(dir, "other_page_001.png"), "");
+
+            var existing = PageImageExporter.ExistingOutputs(dir, "/docs/paper.pdf", [0, 1, 2], 10);
+
+            Assert.Equal([Path.Combine(dir, "paper_page_002.png")], existing);
+        }
+        finally
+        {
+            Directory.Delete(dir, recursive: true);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class PageJumpTests
+{
+    [Theory]
+    [InlineData("247", 246)]
+    [InlineData(" 1 ", 0)]
+    [InlineData("300", 299)]
+    public void Absolute_IsOneBased(string text, int expected)
+        => Assert.Equal(expected, PageJump.Resolve(text, currentPage: 9, pageCount: 300));
+
+    [Theory]
+    [InlineData("+10", 19)]
+    [InlineData("-5", 4)]
+    [InlineData("+ 3", 12)]
+    [InlineData("-9", 0)]
+    public void Relative_IsFromCurrentPage(string text, int expected)
+        => Assert.Equal(expected, PageJump.Resolve(text, currentPage: 9, pageCount: 300));
+
+    [Theory]
+    [InlineData("0")]
+    [InlineData("301")]
+    [InlineData("-10")]
+    [InlineData("+291")]
+    public void OutOfRange_IsRejectedNotClamped(string text)
+        => Assert.Null(PageJump.Resolve(text, currentPage: 9, pageCount: 300));
+
+    [Theory]
+    [InlineData(null)]
+    [InlineData("")]
+    [InlineData("abc")]
+    [InlineData("+")]
+    [InlineData("--5")]
+    [InlineData("1.5")]
+    public void Malformed_IsRejected(string? text)
+        => Assert.Null(PageJump.Resolve(text, currentPage: 9, pageCount: 300));
+}
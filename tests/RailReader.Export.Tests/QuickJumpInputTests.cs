@@ -0,0 +1,64 @@
+using RailReader2.Services;
+using Xunit;
+using Outcome = RailReader2.Services.QuickJumpInput.Outcome;
+
+namespace RailReader.Export.Tests;
+
+public class QuickJumpInputTests
+{
+    [Fact]
+    public void Labels_DigitsThenLetterPairs()
+    {
+        var labels = QuickJumpInput.Labels(12);
+        Assert.Equal(["1", "2", "3", "4", "5", "6", "7", "8", "9", "aa", "ab", "ac"], labels);
+    }
+
+    [Fact]
+    public void Labels_RollOverToNextFirstLetter()
+    {
+        var labels = QuickJumpInput.Labels(9 + 27);
+        Assert.Equal("az", labels[9 + 25]);
+        Assert.Equal("ba", labels[9 + 26]);
+    }
+
+    [Fact]
+    public void Labels_CappedAndUnique()
+    {
+        var labels = QuickJumpInput.Labels(QuickJumpInput.MaxLabels + 50);
+        Assert.Equal(QuickJumpInput.MaxLabels, labels.Count);
+        Assert.Equal(labels.Count, labels.Distinct().Count());
+        Assert.Equal("zz", labels[^1]);
+    }
+
+    [Fact]
+    public void Feed_DigitSelectsImmediately()
+    {
+        var input = new QuickJumpInput(QuickJumpInput.Labels(5));
+        Assert.Equal(Outcome.Selected, input.Feed('3', out int index));
+        Assert.Equal(2, index);
+    }
+
+    [Fact]
+    public void Feed_TwoLettersSelectAfterPending()
+    {
+        var input = new QuickJumpInput(QuickJumpInput.Labels(12));
+        Assert.Equal(Outcome.Pending, input.Feed('A', out _));
+        Assert.Equal("a", input.Typed);
+        Assert.Equal(Outcome.Selected, input.Feed('b', out int index));
+        Assert.Equal(10, index);
+        Assert.Equal("", input.Typed);
+    }
+
+    [Fact]
+    public void Feed_UnknownLabelIsAMiss()
+    {
+        var input = new QuickJumpInput(QuickJumpInput.Labels(4));
+        Assert.Equal(Outcome.NoMatch, input.Feed('7', out int index));
+        Assert.Equal(-1, index);
+
+        input = new QuickJumpInput(QuickJumpInput.Labels(12));
+        input.Feed('a', out _);
+        Assert.Equal(Outcome.NoMatch, input.Feed('z', out _));
+        Assert.Equal("", input.Typed);
+    }
+}
@@ -0,0 +1,36 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class RailTextTests
+{
+    [Fact]
+    public void JoinLines_SeparatesLinesWithSingleSpaces()
+    {
+        Assert.Equal("The quick brown fox jumps over the lazy dog.",
+            RailText.JoinLines(["The quick brown ", "  fox jumps over", null, "", "the lazy dog."]));
+    }
+
+    [Fact]
+    public void JoinLines_RejoinsWordsHyphenatedAtTheBreak()
+    {
+        Assert.Equal("a synthetic example of rail text",
+            RailText.JoinLines(["a synthetic exam-", "ple of rail text"]));
+    }
+
+    [Theory]
+    [InlineData("Smith and Jones-", "Smith reported", "Smith and Jones-Smith reported")]
+    [InlineData("released in pre-", "2020 builds", "released in pre-2020 builds")]
+    [InlineData("values in the range 1 -", "two", "values in the range 1 - two")]
+    public void JoinLines_KeepsHyphensThatArentLineBreakSplits(string first, string second, string expected)
+    {
+        Assert.Equal(expected, RailText.JoinLines([first, second]));
+    }
+
+    [Fact]
+    public void JoinLines_NoLinesIsEmpty()
+    {
+        Assert.Equal("", RailText.JoinLines([]));
+    }
+}
@@ -0,0 +1,76 @@
+using System.Diagnostics;
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class ReadingProgressTests
+{
+    private static long Sec(double s) => (long)(s * Stopwatch.Frequency);
+
+    [Theory]
+    [InlineData(0, 10, 0, 0, 0, 0, 0.0)]     // top of the first page
+    [InlineData(5, 10, 0, 0, 0, 0, 0.5)]     // outside rail mode: page start
+    [InlineData(5, 10, 1, 2, 0, 4, 0.55)]    // second of two blocks → half the page
+    [InlineData(5, 10, 1, 2, 2, 4, 0.575)]   // halfway through that block's lines
+    [InlineData(9, 10, 0, 1, 4, 4, 1.0)]     // past the last line of the last page
+    public void Fraction_CombinesPagesBlocksAndLines(int page, int pages, int block, int blocks,
+        int line, int lines, double expected)
+        => Assert.Equal(expected, ReadingProgress.Fraction(page, pages, block, blocks, line, lines), precision: 6);
+
+    [Fact]
+    public void Fraction_EmptyDocumentIsZero()
+        => Assert.Equal(0, ReadingProgress.Fraction(0, 0, 0, 0, 0, 0));
+
+    [Fact]
+    public void EstimateRemaining_HiddenUntilAMinuteOfReading()
+    {
+        var p = new ReadingProgress();
+        p.Record(0.10, 100, Sec(0));
+        p.Record(0.105, 100, Sec(30));
+        Assert.Null(p.EstimateRemaining(0.105, Sec(30)));
+        p.Record(0.11, 100, Sec(60));
+        Assert.NotNull(p.EstimateRemaining(0.11, Sec(60)));
+    }
+
+    [Fact]
+    public void EstimateRemaining_ExtrapolatesRecentPace()
+    {
+        // 1% per minute with 50% left → 50 minutes.
+        var p = new ReadingProgress();
+        for (int i = 0; i <= 4; i++)
+            p.Record(0.46 + i * 0.01, 50, Sec(i * 60));
+        var left = p.EstimateRemaining(0.50, Sec(240));
+        Assert.NotNull(left);
+        Assert.Equal(50, left!.Value.TotalMinutes, precision: 6);
+    }
+
+    [Fact]
+    public void Jump_RestartsThePaceSample()
+    {
+        var p = new ReadingProgress();
+        p.Record(0.10, 100, Sec(0));
+        p.Record(0.11, 100, Sec(90));
+        p.Record(0.60, 100, Sec(100)); // Go to page: half the document in one step
+        Assert.Null(p.EstimateRemaining(0.60, Sec(100)));
+    }
+
+    [Fact]
+    public void IdleGap_RestartsThePaceSampleAndHidesTheEstimate()
+    {
+        var p = new ReadingProgress();
+        p.Record(0.10, 100, Sec(0));
+        p.Record(0.11, 100, Sec(90));
+        Assert.Null(p.EstimateRemaining(0.11, Sec(90 + 600))); // stepped away for ten minutes
+        p.Record(0.115, 100, Sec(90 + 600));
+        Assert.Null(p.EstimateRemaining(0.115, Sec(90 + 600)));
+    }
+
+    [Theory]
+    [InlineData(20, "<1 min left")]
+    [InlineData(25 * 60, "~25 min left")]
+    [InlineData(120 * 60, "~2 h left")]
+    [InlineData(80 * 60, "~1 h 20 min left")]
+    public void FormatRemaining(double seconds, string expected)
+        => Assert.Equal(expected, ReadingProgress.FormatRemaining(TimeSpan.FromSeconds(seconds)));
+}
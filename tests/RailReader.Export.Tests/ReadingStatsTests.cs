@@ -0,0 +1,96 @@
+using System.Diagnostics;
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class ReadingStatsTests
+{
+    private static long Sec(double s) => (long)(s * Stopwatch.Frequency);
+
+    [Fact]
+    public void Mark_CreditsTimeToThePreviousPosition()
+    {
+        var stats = new ReadingStats();
+        stats.Mark(0, 0, 0, Sec(0));
+        stats.Mark(0, 0, 1, Sec(10));
+        stats.Mark(0, 1, 0, Sec(25));
+        stats.Pause(Sec(30));
+
+        var entries = stats.Entries();
+        Assert.Equal(new[] { (0, 0), (0, 1) }, entries.Select(e => (e.Page, e.Block)));
+        Assert.Equal(25, entries[0].Seconds, precision: 6);
+        Assert.Equal(5, entries[1].Seconds, precision: 6);
+        Assert.Equal(30, stats.TotalSeconds, precision: 6);
+        Assert.Equal(2, stats.LinesRead);
+        Assert.Equal(15, stats.SecondsPerLine!.Value, precision: 6);
+    }
+
+    [Fact]
+    public void OnlyForwardStepsCountAsLinesRead()
+    {
+        var stats = new ReadingStats();
+        stats.Mark(0, 0, 0, Sec(0));
+        stats.Mark(0, 0, 1, Sec(1));  // next line
+        stats.Mark(0, 0, 0, Sec(2));  // back a line
+        stats.Mark(0, 0, 1, Sec(3));  // forward again
+        stats.Mark(0, 1, 0, Sec(4));  // next block's first line
+        stats.Mark(1, 0, 0, Sec(5));  // next page's first block
+        stats.Mark(5, 3, 2, Sec(6));  // jump ahead
+        stats.Mark(5, 3, 2, Sec(7));  // re-seat
+        stats.Mark(5, 2, 0, Sec(8));  // previous block
+        stats.Mark(5, 4, 0, Sec(9));  // skips a block
+        Assert.Equal(4, stats.LinesRead);
+    }
+
+    [Fact]
+    public void IdleGapCountsOnlyUpToTheTimeout()
+    {
+        var stats = new ReadingStats { IdleTimeout = TimeSpan.FromMinutes(2) };
+        stats.Mark(3, 0, 0, Sec(0));
+        stats.Mark(3, 0, 1, Sec(600));
+        Assert.Equal(120, stats.TotalSeconds, precision: 6);
+    }
+
+    [Fact]
+    public void PausedTimeIsNotCounted()
+    {
+        var stats = new ReadingStats();
+        stats.Mark(0, 0, 0, Sec(0));
+        stats.Pause(Sec(10));
+        stats.Mark(0, 0, 0, Sec(50));
+        stats.Pause(Sec(55));
+        Assert.Equal(15, stats.TotalSeconds, precision: 6);
+        // Resuming on the same line isn't a new line read.
+        Assert.Equal(0, stats.LinesRead);
+        Assert.Null(stats.SecondsPerLine);
+    }
+
+    [Fact]
+    public void Flush_BringsTheRunningIntervalUpToDateOnce()
+    {
+        var stats = new ReadingStats();
+        stats.Mark(0, 0, 0, Sec(0));
+        stats.Flush(Sec(20));
+        stats.Flush(Sec(30));
+        Assert.Equal(30, stats.TotalSeconds, precision: 6);
+    }
+
+    [Fact]
+    public void PerPageAndCsv_AreInPageOrderAndOneBased()
+    {
+        var stats = new ReadingStats();
+        stats.Mark(4, 2, 0, Sec(0));
+        stats.Mark(1, 0, 0, Sec(12));
+        stats.Mark(4, 0, 0, Sec(20));
+        stats.Pause(Sec(23.5));
+
+        var pages = stats.PerPage();
+        Assert.Equal(new[] { 1, 4 }, pages.Select(p => p.Page));
+        Assert.Equal(8, pages[0].Seconds, precision: 6);
+        Assert.Equal(15.5, pages[1].Seconds, precision: 6);
+        Assert.Equal(2, stats.PagesVisited);
+        Assert.Equal("page,block,seconds\n2,1,8.0\n5,1,3.5\n5,3,12.0\n",
+            stats.ToCsv().ReplaceLineEndings("\n"));
+    }
+}
@@ -0,0 +1,48 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class ShortcutReferenceTests
+{
+    private static ShortcutReference.Row RowFor(string action)
+        => ShortcutReference.Sections.SelectMany(s => s.Rows).Single(r => r.Action == action);
+
+    [Fact]
+    public void EveryKeymapAction_HasExactlyOneRow()
+    {
+        var actions = ShortcutReference.Sections.SelectMany(s => s.Rows)
+            .Where(r => r.Action is not null).Select(r => r.Action!).ToList();
+
+        Assert.Equal(Keymap.Actions.Select(a => a.Name).Order(), actions.Order());
+    }
+
+    [Fact]
+    public void UnboundAction_ShowsItsDefaultAndAliases()
+    {
+        var keymap = Keymap.Build(null);
+
+        Assert.Equal("Down / S", ShortcutReference.KeysFor(RowFor("next_line"), keymap));
+        Assert.Equal("Ctrl+2", ShortcutReference.KeysFor(RowFor("fit_width"), keymap));
+        Assert.Equal("PgDn / Space", ShortcutReference.KeysFor(RowFor("next_page"), keymap));
+    }
+
+    [Fact]
+    public void BoundAction_ShowsTheUsersChordFirst()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["next_line"] = "K", ["go_to_page"] = "Ctrl+J" });
+
+        Assert.Equal("K / Down / S", ShortcutReference.KeysFor(RowFor("next_line"), keymap));
+        Assert.Equal("Ctrl+J / Ctrl+G", ShortcutReference.KeysFor(RowFor("go_to_page"), keymap));
+    }
+
+    [Fact]
+    public void KeysTakenByAnotherBinding_AreDropped()
+    {
+        var keymap = Keymap.Build(new Dictionary<string, string> { ["scroll_backward"] = "J", ["quick_jump"] = "S" });
+
+        Assert.Equal("", ShortcutReference.KeysFor(RowFor("toggle_jump_mode"), keymap));
+        Assert.Equal("Down", ShortcutReference.KeysFor(RowFor("next_line"), keymap));
+        Assert.Equal("J / Left / A", ShortcutReference.KeysFor(RowFor("scroll_backward"), keymap));
+    }
+}
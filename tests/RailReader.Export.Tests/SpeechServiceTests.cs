@@ -0,0 +1,49 @@
+using RailReader2.Services;
+using Xunit;
+using Backend = RailReader2.Services.SpeechService.Backend;
+
+namespace RailReader.Export.Tests;
+
+public class SpeechServiceTests
+{
+    [Theory]
+    [InlineData("")]
+    [InlineData("   ")]
+    public void BuildCommand_NullForBlankText(string text)
+        => Assert.Null(SpeechService.BuildCommand(Backend.EspeakNg, text, 180));
+
+    [Fact]
+    public void BuildCommand_NullWithoutBackend()
+        => Assert.Null(SpeechService.BuildCommand(Backend.None, "Hello", 180));
+
+    [Fact]
+    public void BuildCommand_EspeakReadsStdinAtClampedRate()
+    {
+        var cmd = SpeechService.BuildCommand(Backend.EspeakNg, "-rf /", 1000)!.Value;
+        Assert.Equal("espeak-ng", cmd.FileName);
+        Assert.Equal(["-s", "400", "--stdin"], cmd.Arguments);
+        // Text that looks like an option never reaches the argument list.
+        Assert.Equal("-rf /", cmd.StandardInput);
+    }
+
+    [Theory]
+    [InlineData(180, "0")]
+    [InlineData(80, "-50")]
+    [InlineData(400, "100")]
+    public void BuildCommand_SpdSayMapsRateAndEndsOptions(int wpm, string rate)
+    {
+        var cmd = SpeechService.BuildCommand(Backend.SpdSay, "-h", wpm)!.Value;
+        Assert.Equal(["-r", rate, "--", "-h"], cmd.Arguments);
+        Assert.Null(cmd.StandardInput);
+    }
+
+    [Fact]
+    public void BuildCommand_SapiTakesTextFromStdin()
+    {
+        var cmd = SpeechService.BuildCommand(Backend.WindowsSapi, "it's \"quoted\"", 240)!.Value;
+        Assert.Equal("powershell", cmd.FileName);
+        Assert.Contains("$s.Rate = 3;", cmd.Arguments[^1]);
+        Assert.DoesNotContain("quoted", cmd.Arguments[^1]);
+        Assert.Equal("it's \"quoted\"", cmd.StandardInput);
+    }
+}
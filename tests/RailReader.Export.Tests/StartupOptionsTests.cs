@@ -0,0 +1,69 @@
+using RailReader2.Services;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class StartupOptionsTests
+{
+    [Fact]
+    public void Parse_ReadsFileAndViewFlags()
+    {
+        var o = StartupOptions.Parse(["paper.pdf", "--page", "37", "--zoom", "250", "--rail"]);
+
+        Assert.Equal(["paper.pdf"], o.Files);
+        Assert.Equal(37, o.Page);
+        Assert.Equal(250, o.ZoomPercent);
+        Assert.True(o.Rail);
+        Assert.False(o.FitWidth);
+        Assert.Empty(o.Errors);
+    }
+
+    [Fact]
+    public void Parse_FlagValueIsNotMistakenForTheFile()
+    {
+        var o = StartupOptions.Parse(["--page", "3", "paper.pdf"]);
+
+        Assert.Equal(["paper.pdf"], o.Files);
+        Assert.Equal(3, o.Page);
+    }
+
+    [Theory]
+    [InlineData("0")]
+    [InlineData("x")]
+    public void Parse_BadPage_IsReportedAndDropped(string value)
+    {
+        var o = StartupOptions.Parse(["paper.pdf", "--page", value]);
+
+        Assert.Null(o.Page);
+        Assert.Single(o.Errors);
+    }
+
+    [Fact]
+    public void Parse_MissingValue_DoesNotSwallowTheNextFlag()
+    {
+        var o = StartupOptions.Parse(["--zoom", "--rail"]);
+
+        Assert.Null(o.ZoomPercent);
+        Assert.True(o.Rail);
+        Assert.Single(o.Errors);
+    }
+
+    [Fact]
+    public void Parse_ZoomWithFitWidth_KeepsFitWidth()
+    {
+        var o = StartupOptions.Parse(["--zoom", "150%", "--fit-width"]);
+
+        Assert.True(o.FitWidth);
+        Assert.Null(o.ZoomPercent);
+        Assert.Contains(o.Errors, e => e.Contains("--fit-width"));
+    }
+
+    [Fact]
+    public void Parse_UnknownOptionAndHelp()
+    {
+        var o = StartupOptions.Parse(["--headless-export", "-h"]);
+
+        Assert.True(o.Help);
+        Assert.Equal(["Unknown option --headless-export"], o.Errors);
+    }
+}
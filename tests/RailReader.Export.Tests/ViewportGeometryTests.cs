@@ -0,0 +1,50 @@
+using RailReader2.Views;
+using Xunit;
+
+namespace RailReader.Export.Tests;
+
+public class ViewportGeometryTests
+{
+    // A 600×800 page at zoom 1 in a 1000×700 view.
+    private const double PageW = 600, PageH = 800, ViewW = 1000, ViewH = 700;
+
+    [Theory]
+    [InlineData(200, 0)]      // centred horizontally, top aligned
+    [InlineData(-500, -700)]  // only the bottom-right corner shows
+    [InlineData(900, 600)]    // only the top-left corner shows
+    public void IsPageOffScreen_FalseWhileAnyPartIsVisible(double ox, double oy)
+        => Assert.False(ViewportGeometry.IsPageOffScreen(ox, oy, 1.0, PageW, PageH, ViewW, ViewH));
+
+    [Theory]
+    [InlineData(-600, 0)]     // past the left edge
+    [InlineData(1000, 0)]     // past the right edge
+    [InlineData(0, -800)]     // above the top
+    [InlineData(0, 700)]      // below the bottom
+    public void IsPageOffScreen_TrueWhenPageIsFullyOutside(double ox, double oy)
+        => Assert.True(ViewportGeometry.IsPageOffScreen(ox, oy, 1.0, PageW, PageH, ViewW, ViewH));
+
+    [Fact]
+    public void IsPageOffScreen_AfterWindowShrinksWhileTabHidden()
+    {
+        // Camera left panned to the far right of a wide window; the window then shrank to 800 px.
+        const double zoom = 2.0, ox = 1200, oy = 50;
+        Assert.False(ViewportGeometry.IsPageOffScreen(ox, oy, zoom, PageW, PageH, 2000, ViewH));
+        Assert.True(ViewportGeometry.IsPageOffScreen(ox, oy, zoom, PageW, PageH, 800, ViewH));
+    }
+
+    [Fact]
+    public void IsPageOffScreen_AccountsForZoom()
+    {
+        // At zoom 1 the page ends at x = -100; at zoom 2 it reaches x = 500.
+        Assert.True(ViewportGeometry.IsPageOffScreen(-700, 0, 1.0, PageW, PageH, ViewW, ViewH));
+        Assert.False(ViewportGeometry.IsPageOffScreen(-700, 0, 2.0, PageW, PageH, ViewW, ViewH));
+    }
+
+    [Theory]
+    [InlineData(0, 700, 1.0, 600)]    // view not laid out
+    [InlineData(1000, 0, 1.0, 600)]
+    [InlineData(1000, 700, 0.0, 600)] // no zoom
+    [InlineData(1000, 700, 1.0, 0)]   // no page loaded
+    public void IsPageOffScreen_FalseForDegenerateSizes(double viewW, double viewH, double zoom, double pageW)
+        => Assert.False(ViewportGeometry.IsPageOffScreen(-5000, -5000, zoom, pageW, PageH, viewW, viewH));
+}